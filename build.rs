@@ -0,0 +1,68 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    let max = if env::var_os("CARGO_FEATURE_CONSTS_1024").is_some() {
+        1024
+    } else if env::var_os("CARGO_FEATURE_CONSTS_256").is_some() {
+        256
+    } else if env::var_os("CARGO_FEATURE_CONSTS_64").is_some() {
+        64
+    } else {
+        16
+    };
+    let nums = (1..=max).map(|n| n.to_string()).collect::<Vec<_>>().join(", ");
+    let table = format!(
+        "const TABLE_MAX: i32 = {max};\n\nmacro_rules! with_table {{\n    ($callback:ident) => {{\n        $callback! {{ {nums} }}\n    }};\n}}\n"
+    );
+    let out_dir = env::var_os("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("table.rs"), table).unwrap();
+
+    if env::var_os("CARGO_FEATURE_VERIFY_TABLE").is_some() {
+        fs::write(Path::new(&out_dir).join("verify.rs"), verify(max)).unwrap();
+    }
+}
+
+/// Generates a test per operation, which checks every pair of `-range..=range` against `i32` arithmetic.
+/// The range is `TYPENUM_ALIAS_VERIFY_RANGE` or the square root of the table maximum, products of the pairs
+/// must fit into the table.
+fn verify(max: i32) -> String {
+    println!("cargo:rerun-if-env-changed=TYPENUM_ALIAS_VERIFY_RANGE");
+    let range = match env::var("TYPENUM_ALIAS_VERIFY_RANGE") {
+        Ok(range) => {
+            let range: i32 = range.parse().expect("TYPENUM_ALIAS_VERIFY_RANGE must be an integer");
+            assert!(
+                range * range <= max,
+                "TYPENUM_ALIAS_VERIFY_RANGE squared exceeds the conversion table"
+            );
+            range
+        }
+        Err(_) => (1..).take_while(|range| range * range <= max).last().unwrap_or(0),
+    };
+
+    let ops = [
+        ("add", "assert_eq!(value::<Sum<{l}, {r}>>(), {a} + {b});"),
+        ("sub", "assert_eq!(value::<Diff<{l}, {r}>>(), {a} - {b});"),
+        ("mul", "assert_eq!(value::<Prod<{l}, {r}>>(), {a} * {b});"),
+        ("div", "assert_eq!(value::<Quot<{l}, {r}>>(), {a} / {b});"),
+        ("min", "assert_eq!(value::<Minimum<{l}, {r}>>(), i32::min({a}, {b}));"),
+        ("max", "assert_eq!(value::<Maximum<{l}, {r}>>(), i32::max({a}, {b}));"),
+        ("gcd", "assert_eq!(value::<Gcf<{l}, {r}>>(), gcd_i32({a}, {b}));"),
+        ("cmp", "assert_eq!(Compare::<{l}, {r}>::to_ordering(), i32::cmp(&{a}, &{b}));"),
+    ];
+    let mut out = String::new();
+    for (name, check) in ops {
+        writeln!(out, "#[test]\nfn {name}() {{").unwrap();
+        for a in -range..=range {
+            for b in (-range..=range).filter(|&b| name != "div" || b != 0) {
+                let check = check
+                    .replace("{l}", &format!("Const<{a}>"))
+                    .replace("{r}", &format!("Const<{b}>"))
+                    .replace("{a}", &format!("{a}_i32"))
+                    .replace("{b}", &format!("{b}_i32"));
+                writeln!(out, "    {check}").unwrap();
+            }
+        }
+        writeln!(out, "}}\n").unwrap();
+    }
+    out
+}
@@ -0,0 +1,255 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashMap;
+use syn::{
+    parse_quote, punctuated::Punctuated, visit_mut::VisitMut, FnArg, GenericArgument, GenericParam,
+    Generics, Ident, ItemFn, Token, Type, TypeParamBound, WherePredicate,
+};
+
+/// How a type parameter is converted from `Const<N>`
+#[derive(Clone, Copy)]
+enum Conversion {
+    Integer,
+    Unsigned,
+}
+
+impl Conversion {
+    fn of(bounds: &Punctuated<TypeParamBound, Token![+]>) -> Option<Self> {
+        bounds.iter().find_map(|bound| match bound {
+            TypeParamBound::Trait(bound) => {
+                match bound.path.segments.last()?.ident.to_string().as_str() {
+                    "Integer" => Some(Self::Integer),
+                    "Unsigned" => Some(Self::Unsigned),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+    }
+
+    fn convert(self, source: &Type) -> Type {
+        match self {
+            Self::Integer => parse_quote!(::typenum_alias::Typenum<#source>),
+            Self::Unsigned => {
+                parse_quote!(::typenum_alias::ops::Magnitude<::typenum_alias::Typenum<#source>>)
+            }
+        }
+    }
+}
+
+/// Replaces converted type parameters with their `typenum` form
+struct Substitute<'a>(&'a HashMap<Ident, Type>);
+
+impl VisitMut for Substitute<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        if let Type::Path(path) = ty {
+            if path.qself.is_none() {
+                if let Some(ident) = path.path.get_ident() {
+                    if let Some(converted) = self.0.get(ident) {
+                        *ty = converted.clone();
+                        return;
+                    }
+                }
+            }
+        }
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+fn bounded_param(
+    predicate: &WherePredicate,
+) -> Option<(&Ident, &Punctuated<TypeParamBound, Token![+]>)> {
+    match predicate {
+        WherePredicate::Type(predicate) => match &predicate.bounded_ty {
+            Type::Path(path) if path.qself.is_none() => {
+                Some((path.path.get_ident()?, &predicate.bounds))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Arguments of the call to the original function. Lifetimes are omitted, since they may be late-bound.
+fn generic_args(generics: &Generics, converted: &HashMap<Ident, Type>) -> Vec<GenericArgument> {
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                Some(
+                    converted.get(ident).map_or_else(
+                        || parse_quote!(#ident),
+                        |ty| GenericArgument::Type(ty.clone()),
+                    ),
+                )
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                Some(parse_quote!(#ident))
+            }
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect()
+}
+
+/// Which kind of generic parameters the callers pass instead of `typenum` types
+#[derive(Clone, Copy)]
+pub enum Mode {
+    /// `N: ToTypenum`, i. e. `Const<N>`, in a function with the same name
+    Constify,
+    /// `const N: i32` in a function named `{name}_const`
+    Wrapper,
+}
+
+impl Mode {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Constify => "constify",
+            Self::Wrapper => "const_wrapper",
+        }
+    }
+
+    fn source(self, param: &Ident) -> Type {
+        match self {
+            Self::Constify => parse_quote!(#param),
+            Self::Wrapper => parse_quote!(::typenum_alias::Const<#param>),
+        }
+    }
+}
+
+/// Builds the signature of the function converting `Const`s & calling `original`. Returns it with the
+/// names of its arguments & the arguments of the call to `original`.
+fn convert_signature(
+    original: &ItemFn,
+    mode: Mode,
+) -> syn::Result<(ItemFn, Vec<Ident>, Vec<GenericArgument>)> {
+    let mut conversions = HashMap::new();
+    for param in original.sig.generics.type_params() {
+        if let Some(conversion) = Conversion::of(&param.bounds) {
+            conversions.insert(param.ident.clone(), conversion);
+        }
+    }
+    let predicates =
+        original.sig.generics.where_clause.iter().flat_map(|clause| &clause.predicates);
+    for predicate in predicates {
+        if let Some((ident, bounds)) = bounded_param(predicate) {
+            if let Some(conversion) = Conversion::of(bounds) {
+                conversions.insert(ident.clone(), conversion);
+            }
+        }
+    }
+    if conversions.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &original.sig.generics,
+            format!("`{}` expects type parameters bounded by `Unsigned` or `Integer`", mode.name()),
+        ));
+    }
+    let converted: HashMap<_, _> = conversions
+        .iter()
+        .map(|(ident, conversion)| (ident.clone(), conversion.convert(&mode.source(ident))))
+        .collect();
+
+    let mut outer = original.clone();
+    let sig = &mut outer.sig;
+    let mut predicates: Vec<WherePredicate> = Vec::new();
+    for param in &mut sig.generics.params {
+        let GenericParam::Type(type_param) = param else { continue };
+        let Some(conversion) = conversions.get(&type_param.ident) else { continue };
+        let ident = type_param.ident.clone();
+        let source = mode.source(&ident);
+        let ty = &converted[&ident];
+        let bounds = &type_param.bounds;
+        if let Conversion::Unsigned = conversion {
+            predicates.push(
+                parse_quote!(::typenum_alias::Typenum<#source>: ::typenum_alias::ops::ToUnsigned),
+            );
+        }
+        if !bounds.is_empty() {
+            predicates.push(parse_quote!(#ty: #bounds));
+        }
+        match mode {
+            Mode::Constify => type_param.bounds = parse_quote!(::typenum_alias::ToTypenum),
+            Mode::Wrapper => {
+                predicates.push(parse_quote!(#source: ::typenum_alias::ToTypenum));
+                *param = parse_quote!(const #ident: i32);
+            }
+        }
+    }
+    let where_clause = sig.generics.make_where_clause();
+    for mut predicate in core::mem::take(&mut where_clause.predicates) {
+        Substitute(&converted).visit_where_predicate_mut(&mut predicate);
+        predicates.push(predicate);
+    }
+    where_clause.predicates.extend(predicates);
+
+    let mut args = Vec::new();
+    for (i, input) in sig.inputs.iter_mut().enumerate() {
+        match input {
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new_spanned(
+                    receiver,
+                    format!("`{}` doesn't support methods with a receiver", mode.name()),
+                ))
+            }
+            FnArg::Typed(arg) => {
+                if let Type::ImplTrait(_) = *arg.ty {
+                    return Err(syn::Error::new_spanned(
+                        &arg.ty,
+                        format!("`{}` doesn't support `impl Trait` arguments", mode.name()),
+                    ));
+                }
+                let ident = format_ident!("__arg{}", i);
+                *arg.pat = parse_quote!(#ident);
+                Substitute(&converted).visit_type_mut(&mut arg.ty);
+                args.push(ident);
+            }
+        }
+    }
+    Substitute(&converted).visit_return_type_mut(&mut sig.output);
+
+    let generic_args = generic_args(&original.sig.generics, &converted);
+    Ok((outer, args, generic_args))
+}
+
+fn call(
+    original: &ItemFn,
+    path: &TokenStream,
+    generic_args: &[GenericArgument],
+    args: &[Ident],
+) -> TokenStream {
+    let mut call = quote!(#path::<#(#generic_args),*>(#(#args),*));
+    if original.sig.asyncness.is_some() {
+        call = quote!(#call.await);
+    }
+    if original.sig.unsafety.is_some() {
+        call = quote!(unsafe { #call });
+    }
+    call
+}
+
+pub fn constify(original: ItemFn) -> syn::Result<TokenStream> {
+    let (mut outer, args, generic_args) = convert_signature(&original, Mode::Constify)?;
+    let mut inner = original;
+    inner.attrs.clear();
+    inner.vis = syn::Visibility::Inherited;
+    inner.sig.ident = format_ident!("inner");
+    let call = call(&inner, &quote!(inner), &generic_args, &args);
+    *outer.block = parse_quote!({
+        #inner
+        #call
+    });
+    Ok(quote!(#outer))
+}
+
+pub fn const_wrapper(original: &ItemFn) -> syn::Result<TokenStream> {
+    let (mut wrapper, args, generic_args) = convert_signature(original, Mode::Wrapper)?;
+    let name = &original.sig.ident;
+    let doc = format!(" Const-generic wrapper of [`{name}`]");
+    wrapper.attrs = vec![parse_quote!(#[doc = #doc])];
+    wrapper.sig.ident = format_ident!("{}_const", name);
+    let call = call(original, &quote!(#name), &generic_args, &args);
+    *wrapper.block = parse_quote!({ #call });
+    Ok(quote!(#original #wrapper))
+}
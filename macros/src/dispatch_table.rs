@@ -0,0 +1,50 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    Attribute, ExprPath, Ident, Token, Type, Visibility,
+};
+
+use crate::range;
+
+/// `static NAME: [fn(); 0..=16] = function;`
+pub struct Input {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    ty: Type,
+    range: core::ops::RangeInclusive<i64>,
+    function: ExprPath,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![static]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let content;
+        bracketed!(content in input);
+        let ty = content.parse()?;
+        content.parse::<Token![;]>()?;
+        let range = range::parse(&content)?;
+        input.parse::<Token![=]>()?;
+        let function = input.parse()?;
+        input.parse::<Option<Token![;]>>()?;
+        Ok(Self { attrs, vis, name, ty, range, function })
+    }
+}
+
+pub fn expand(Input { attrs, vis, name, ty, range, function }: Input) -> TokenStream {
+    let len = range.clone().count();
+    let entries = range.map(|n| {
+        let n = range::literal(n);
+        quote!(#function::<::typenum_alias::Const<#n>>)
+    });
+    quote! {
+        #(#attrs)*
+        #vis static #name: [#ty; #len] = [#(#entries),*];
+    }
+}
@@ -0,0 +1,21 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{GenericParam, ItemImpl};
+
+use crate::range::{substitute, Args};
+
+pub fn expand(args: &Args, mut item: ItemImpl) -> syn::Result<TokenStream> {
+    let len = item.generics.params.len();
+    item.generics.params = core::mem::take(&mut item.generics.params)
+        .into_iter()
+        .filter(|param| !matches!(param, GenericParam::Const(param) if param.ident == args.param))
+        .collect();
+    if item.generics.params.len() == len {
+        return Err(syn::Error::new_spanned(
+            &item.generics,
+            format!("expected a const parameter `{}`", args.param),
+        ));
+    }
+    let item = item.into_token_stream();
+    Ok(args.range.clone().map(|value| substitute(item.clone(), &args.param, value)).collect())
+}
@@ -0,0 +1,41 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    Path, Token,
+};
+
+use crate::range;
+
+/// `Trait for 0..=64` with an optional `{ body }`
+pub struct Input {
+    trait_: Path,
+    range: core::ops::RangeInclusive<i64>,
+    body: TokenStream,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let trait_ = input.parse()?;
+        input.parse::<Token![for]>()?;
+        let range = range::parse(input)?;
+        let body = if input.is_empty() {
+            TokenStream::new()
+        } else {
+            let body;
+            braced!(body in input);
+            body.parse()?
+        };
+        Ok(Self { trait_, range, body })
+    }
+}
+
+pub fn expand(Input { trait_, range, body }: Input) -> TokenStream {
+    range
+        .map(|value| {
+            let value = range::literal(value);
+            quote!(impl #trait_ for ::typenum_alias::Const<#value> { #body })
+        })
+        .collect()
+}
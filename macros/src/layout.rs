@@ -0,0 +1,173 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    punctuated::Punctuated, Data, DataStruct, DeriveInput, Error, Field, Fields, LitInt, Meta,
+    Token, Type,
+};
+
+/// Checks that the struct is `#[repr(C)]` without `packed` or `align`, which would change the computed layout
+fn check_repr(input: &DeriveInput) -> syn::Result<()> {
+    let mut c = false;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let reprs = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for repr in reprs {
+            if repr.path().is_ident("C") {
+                c = true;
+            } else {
+                return Err(Error::new_spanned(repr, "`ConstLayout` supports only `#[repr(C)]`"));
+            }
+        }
+    }
+    if c {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(&input.ident, "`ConstLayout` requires `#[repr(C)]`"))
+    }
+}
+
+/// The non-generic struct, which the derive of `name` is applied to
+fn fields<'a>(input: &'a DeriveInput, name: &str) -> syn::Result<&'a DataStruct> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            format!("`{name}` can be derived only for structs"),
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            format!("`{name}` can't be derived for generic structs"),
+        ));
+    }
+    Ok(data)
+}
+
+/// Implements `ConstLayout` with the `#[repr(C)]` algorithm: each field is placed at the offset
+/// rounded up to its alignment, & the size is rounded up to the largest alignment.
+/// The layout is a const expression rather than `Sum` & `Maximum`, so it isn't limited by the conversion table.
+pub fn expand_layout(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let data = fields(input, "ConstLayout")?;
+    check_repr(input)?;
+
+    let tys = data.fields.iter().map(|field| &field.ty);
+    let layout = quote!({
+        let (mut offset, mut align) = (0_usize, 1_usize);
+        #(
+            let field_align = <::typenum_alias::size::AlignOf<#tys>>::USIZE;
+            offset = offset.div_ceil(field_align) * field_align + <::typenum_alias::size::SizeOf<#tys>>::USIZE;
+            if field_align > align {
+                align = field_align;
+            }
+        )*
+        (offset.div_ceil(align) * align, align)
+    });
+
+    let name = &input.ident;
+    Ok(quote! {
+        impl ::typenum_alias::size::ConstLayout for #name {
+            type Size = ::typenum_alias::Const<{ #layout.0 as i32 }>;
+            type Align = ::typenum_alias::Const<{ #layout.1 as i32 }>;
+        }
+
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<#name>() == <::typenum_alias::size::SizeOf<#name>>::USIZE
+                && ::core::mem::align_of::<#name>() == <::typenum_alias::size::AlignOf<#name>>::USIZE,
+            "the layout computed by `ConstLayout` differs from the layout of the compiler",
+        );
+    })
+}
+
+/// The size of a field as `usize`: `#[const_size(4)]`, `#[const_size(Const<4>)]` or `SIZE` of its type,
+/// where `const_size`, `ConstSize` & `SIZE` are `attr`, `size` & `size_const`
+fn field_size(field: &Field, attr: &str, size: &str, size_const: &str) -> syn::Result<TokenStream> {
+    let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident(attr)) else {
+        let ty = &field.ty;
+        let size = format_ident!("{size}");
+        let size_const = format_ident!("{size_const}");
+        return Ok(quote!(<#ty as ::typenum_alias::size::#size>::#size_const));
+    };
+    if let Ok(n) = attr.parse_args::<LitInt>() {
+        return Ok(quote!(#n));
+    }
+    let ty = attr.parse_args::<Type>()?;
+    Ok(quote!(<#ty>::USIZE))
+}
+
+/// Implements `ConstSize` as the sum of the wire sizes of the fields.
+/// The sum is a const expression rather than `Sum`, so it isn't limited by the conversion table.
+pub fn expand_size(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let data = fields(input, "ConstSize")?;
+    let sizes = data
+        .fields
+        .iter()
+        .map(|field| field_size(field, "const_size", "ConstSize", "SIZE"))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    Ok(quote! {
+        impl ::typenum_alias::size::ConstSize for #name {
+            type Size = ::typenum_alias::Const<{ (0 #(+ #sizes)*) as i32 }>;
+            const SIZE: usize = 0 #(+ #sizes)*;
+        }
+    })
+}
+
+/// The sum of the maximum serialized sizes of `fields` as `usize`
+fn max_sizes(fields: &Fields) -> syn::Result<TokenStream> {
+    let sizes = fields
+        .iter()
+        .map(|field| field_size(field, "max_size", "MaxSerializedSize", "MAX_SIZE"))
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote!((0 #(+ #sizes)*)))
+}
+
+/// The size of the varint of `n`
+fn varint_len(mut n: usize) -> usize {
+    let mut len = 1;
+    while n >= 0x80 {
+        n >>= 7;
+        len += 1;
+    }
+    len
+}
+
+/// Implements `MaxSerializedSize` with the `postcard` format: a struct is the sum of its fields,
+/// an enum is the varint of the largest variant index & the largest variant
+pub fn expand_max_size(input: &DeriveInput) -> syn::Result<TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`MaxSerializedSize` can't be derived for generic types",
+        ));
+    }
+    let size = match &input.data {
+        Data::Struct(data) => max_sizes(&data.fields)?,
+        Data::Enum(data) if data.variants.is_empty() => quote!(0),
+        Data::Enum(data) => {
+            let tag = varint_len(data.variants.len() - 1);
+            let mut max = quote!(0);
+            for variant in &data.variants {
+                let size = max_sizes(&variant.fields)?;
+                max = quote!({
+                    let (a, b) = (#max, #size);
+                    if a > b { a } else { b }
+                });
+            }
+            quote!((#tag + #max))
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`MaxSerializedSize` can't be derived for unions",
+            ));
+        }
+    };
+
+    let name = &input.ident;
+    Ok(quote! {
+        impl ::typenum_alias::size::MaxSerializedSize for #name {
+            type Size = ::typenum_alias::Const<{ #size as i32 }>;
+            const MAX_SIZE: usize = #size;
+        }
+    })
+}
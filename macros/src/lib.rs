@@ -0,0 +1,381 @@
+//! Procedural macros for [`typenum_alias`](https://docs.rs/typenum_alias). Use them through its `macros` feature.
+
+#![deny(clippy::pedantic)]
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemFn, ItemImpl};
+
+mod convert;
+mod dispatch_table;
+mod for_each_const;
+mod impl_for_consts;
+mod layout;
+mod match_const;
+mod range;
+mod seq;
+mod with_const;
+
+/// Makes a function written against `typenum` callable with `Const<N>`.
+///
+/// Type parameters bounded by `Unsigned` or `Integer` become `Const<N>` for the callers, & are converted
+/// to `typenum` before calling the original function. Their original bounds are applied to the converted
+/// type, so errors mention `Const<N>` instead of binary trees.
+///
+/// ```rust
+/// use typenum_alias::{constify, Const};
+/// use typenum::{Unsigned, Integer};
+///
+/// #[constify]
+/// fn bytes<N: Unsigned>() -> usize {
+///     N::USIZE
+/// }
+///
+/// #[constify]
+/// fn negate<I>() -> i32
+/// where
+///     I: Integer,
+/// {
+///     -I::I32
+/// }
+///
+/// assert_eq!(bytes::<Const<16>>(), 16);
+/// assert_eq!(negate::<Const<5>>(), -5);
+/// ```
+#[proc_macro_attribute]
+pub fn constify(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return syn::Error::new_spanned(attr, "`constify` doesn't take arguments")
+            .to_compile_error()
+            .into();
+    }
+    let item = parse_macro_input!(item as ItemFn);
+    convert::constify(item).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Repeats an impl block for every value of its const parameter in a range.
+///
+/// The parameter is replaced with a literal, so the impl may use `Const<N>`, `Typenum<Const<N>>` & other
+/// conversions, which don't work with a generic parameter. This bridges `impl<const N: usize>` blocks
+/// to traits demanding `typenum` types, e. g. `OutputSizeUser` from `digest`.
+///
+/// ```rust
+/// use typenum_alias::{for_each_const, ops::Magnitude, Const, Typenum};
+/// use typenum::{Unsigned, U16};
+///
+/// trait Len {
+///     type Len: Unsigned;
+/// }
+///
+/// struct Buf<const N: usize>([u8; N]);
+///
+/// #[for_each_const(N in 1..=16)]
+/// impl<const N: usize> Len for Buf<N> {
+///     type Len = Magnitude<Typenum<Const<N>>>;
+/// }
+///
+/// let _: U16 = <Buf<16> as Len>::Len::new();
+/// ```
+#[proc_macro_attribute]
+pub fn for_each_const(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as range::Args);
+    let item = parse_macro_input!(item as ItemImpl);
+    for_each_const::expand(&args, item).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Generates a const-generic wrapper of a function written against `typenum`.
+///
+/// The wrapper is named `{name}_const`. Type parameters bounded by `Unsigned` or `Integer` become
+/// `const N: i32` parameters, which are converted to `typenum` before calling the original function.
+///
+/// ```rust
+/// use typenum_alias::const_wrapper;
+/// use typenum::Unsigned;
+///
+/// #[const_wrapper]
+/// fn bytes<N: Unsigned>(scale: usize) -> usize {
+///     N::USIZE * scale
+/// }
+///
+/// assert_eq!(bytes_const::<16>(2), 32);
+/// assert_eq!(bytes::<typenum::U16>(2), 32);
+/// ```
+#[proc_macro_attribute]
+pub fn const_wrapper(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        return syn::Error::new_spanned(attr, "`const_wrapper` doesn't take arguments")
+            .to_compile_error()
+            .into();
+    }
+    let item = parse_macro_input!(item as ItemFn);
+    convert::const_wrapper(&item).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Repeats a block once per value in a range: `seq!(N in 0..8 { ... })`.
+///
+/// `N` is replaced with a literal, so the block may use it both as a value & as `Const<N>`.
+/// `name~N` pastes the value into an identifier. If the block contains `#(...)*` sections, only these are
+/// repeated, which builds arrays & match arms.
+///
+/// ```rust
+/// use typenum_alias::{seq, Const, Typenum};
+/// use typenum::Integer;
+///
+/// seq!(N in 1..=4 {
+///     fn kernel~N(x: u32) -> u32 {
+///         x << Typenum::<Const<N>>::I32
+///     }
+/// });
+///
+/// static SQUARES: [i32; 4] = seq!(N in 0..4 { [#(N * N,)*] });
+///
+/// assert_eq!(kernel3(1), 8);
+/// assert_eq!(SQUARES, [0, 1, 4, 9]);
+/// ```
+///
+/// Negative values are wrapped in braces only inside `<...>`, so they're valid in patterns too:
+///
+/// ```rust
+/// use typenum_alias::{seq, Const, Typenum};
+/// use typenum::Integer;
+///
+/// fn scale(step: i32) -> Option<i32> {
+///     seq!(N in -2..=2 {
+///         match step {
+///             #(N => Some(N * 10),)*
+///             _ => None,
+///         }
+///     })
+/// }
+///
+/// seq!(N in -2..0 {
+///     assert_eq!(Typenum::<Const<N>>::I32, N);
+/// });
+///
+/// assert_eq!(scale(-1), Some(-10));
+/// assert_eq!(scale(2), Some(20));
+/// assert_eq!(scale(3), None);
+/// ```
+#[proc_macro]
+pub fn seq(input: TokenStream) -> TokenStream {
+    seq::expand(parse_macro_input!(input as seq::Input)).into()
+}
+
+/// Implements a trait for every `Const<N>` in a range: `impl_for_consts!(Trait for 0..=64)`.
+///
+/// The impl body may follow the range in braces, it refers to the constant as `Self`.
+///
+/// ```rust
+/// use typenum_alias::{impl_for_consts, Const, ToTypenum};
+/// use typenum::Integer;
+///
+/// trait Small {}
+/// impl_for_consts!(Small for 0..=8);
+///
+/// trait Doubled {
+///     fn doubled() -> i32;
+/// }
+/// impl_for_consts!(Doubled for -4..4 {
+///     fn doubled() -> i32 {
+///         <Self as ToTypenum>::Output::I32 * 2
+///     }
+/// });
+///
+/// fn small<N: Small>(_: N) {}
+///
+/// small(Const::<8>);
+/// assert_eq!(<Const<-3>>::doubled(), -6);
+/// ```
+#[proc_macro]
+pub fn impl_for_consts(input: TokenStream) -> TokenStream {
+    impl_for_consts::expand(parse_macro_input!(input as impl_for_consts::Input)).into()
+}
+
+/// Matches a runtime integer against every `Const` in a range: `with_const!(value, N in 0..=16 => body)`.
+///
+/// The body is evaluated with `N` being the matching `Const<N>` type & its result wrapped in `Some`.
+/// Values outside of the range result in `None`. Unlike a generic function, the body may capture local variables.
+///
+/// ```rust
+/// use typenum_alias::{with_const, Typenum};
+/// use typenum::Integer;
+///
+/// let scale = 2;
+/// let scaled = |value: i32| with_const!(value, N in -8..8 => scale * Typenum::<N>::I32);
+///
+/// assert_eq!(scaled(-3), Some(-6));
+/// assert_eq!(scaled(8), None);
+/// ```
+#[proc_macro]
+pub fn with_const(input: TokenStream) -> TokenStream {
+    with_const::expand(parse_macro_input!(input as with_const::Input)).into()
+}
+
+/// Builds a static table of function pointers, one per `Const` in a range.
+///
+/// `static NAME: [fn(..); a..=b] = function;` instantiates `function::<Const<N>>` for every `N` in the range.
+/// The entry for `N` is at index `N - a`, so runtime selection is a single bounds-checked index.
+///
+/// ```rust
+/// use typenum_alias::{dispatch_table, ToTypenum, Typenum};
+/// use typenum::Integer;
+///
+/// fn shift<N: ToTypenum>(x: u32) -> u32 {
+///     x << Typenum::<N>::I32
+/// }
+///
+/// dispatch_table!(static SHIFTS: [fn(u32) -> u32; 0..8] = shift);
+///
+/// assert_eq!(SHIFTS[3](1), 8);
+/// assert_eq!(SHIFTS.get(8), None);
+/// ```
+#[proc_macro]
+pub fn dispatch_table(input: TokenStream) -> TokenStream {
+    dispatch_table::expand(parse_macro_input!(input as dispatch_table::Input)).into()
+}
+
+/// Matches a runtime integer against chosen constants: `match_const!(value { N @ 1 | 4..=8 => body, _ => fallback })`.
+///
+/// Arms are integers or ranges separated by `|`. `N @` brings the matching `Const<N>` type into the scope of the arm,
+/// which is instantiated once per value.
+///
+/// ```rust
+/// use typenum_alias::{match_const, Typenum};
+/// use typenum::Integer;
+///
+/// fn describe(value: i32) -> i32 {
+///     match_const!(value {
+///         N @ 1 | 2 | 4 => Typenum::<N>::I32 * 10,
+///         -8..=-1 => -1,
+///         _ => 0,
+///     })
+/// }
+///
+/// assert_eq!(describe(4), 40);
+/// assert_eq!(describe(-3), -1);
+/// assert_eq!(describe(3), 0);
+/// ```
+#[proc_macro]
+pub fn match_const(input: TokenStream) -> TokenStream {
+    match_const::expand(parse_macro_input!(input as match_const::Input)).into()
+}
+
+/// Implements `size::ConstLayout` for a `#[repr(C)]` struct, so its size & alignment are `Const`s:
+/// `SizeOf<Header>` & `AlignOf<Header>`.
+///
+/// The fields must implement `ConstLayout` too. The computed layout is checked against the compiler's at compile time.
+///
+/// ```rust
+/// use typenum_alias::{size::{AlignOf, SizeOf}, Const, ConstLayout};
+///
+/// #[derive(ConstLayout)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     len: u32,
+///     flags: [u8; 3],
+/// }
+///
+/// let _: SizeOf<Header> = Const::<12>;
+/// let _: AlignOf<Header> = Const::<4>;
+///
+/// // The size may be outside of the conversion table
+/// #[derive(ConstLayout)]
+/// #[repr(C)]
+/// struct Record {
+///     id: u64,
+///     timestamp: u64,
+///     header: Header,
+///     kind: u8,
+/// }
+///
+/// let _: SizeOf<Record> = Const::<32>;
+/// let _: AlignOf<Record> = Const::<8>;
+/// assert_eq!(<SizeOf<Record>>::USIZE, size_of::<Record>());
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::ConstLayout;
+///
+/// // error: `ConstLayout` requires `#[repr(C)]`
+/// #[derive(ConstLayout)]
+/// struct Header {
+///     kind: u8,
+///     len: u32,
+/// }
+/// ```
+#[proc_macro_derive(ConstLayout)]
+pub fn derive_const_layout(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    layout::expand_layout(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Implements `size::ConstSize` for a struct as the sum of the wire sizes of its fields, without padding:
+/// `WireSize<Header>` as `Const` & `Header::SIZE` as `usize`. The sum may be outside of the conversion table.
+///
+/// The fields must implement `ConstSize` too, or declare their size with `#[const_size(N)]`.
+///
+/// ```rust
+/// use typenum_alias::{size::{ConstSize, WireSize}, Const, ConstSize};
+///
+/// struct Varint(u64);
+///
+/// #[derive(ConstSize)]
+/// struct Header {
+///     kind: u8,
+///     len: u32,
+///     #[const_size(10)]
+///     id: Varint,
+///     mac: [u8; 16],
+/// }
+///
+/// #[derive(ConstSize)]
+/// struct Frame(Header, Header, [u8; 16]);
+///
+/// let _: WireSize<Header> = Const::<31>;
+/// let _: WireSize<Frame> = Const::<78>;
+/// let buffer = [0_u8; Header::SIZE];
+/// assert_eq!(buffer.len(), 31);
+/// ```
+#[proc_macro_derive(ConstSize, attributes(const_size))]
+pub fn derive_const_size(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    layout::expand_size(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Implements `size::MaxSerializedSize` for a struct or an enum as the upper bound of its size serialized
+/// by `postcard`: `MaxSerialized<Message>` as `Const` & `Message::MAX_SIZE` as `usize`, so buffers can be
+/// allocated large enough at compile time.
+///
+/// A struct is the sum of its fields, an enum is the varint of its variant index & its largest variant.
+/// The fields must implement `MaxSerializedSize` too, or declare their size with `#[max_size(N)]`.
+///
+/// ```rust
+/// use typenum_alias::{size::{MaxSerialized, MaxSerializedSize}, Const, MaxSerializedSize};
+///
+/// #[derive(MaxSerializedSize)]
+/// struct Reading {
+///     sensor: u8,
+///     value: i16,
+///     #[max_size(2)]
+///     name: &'static str,
+/// }
+///
+/// #[derive(MaxSerializedSize)]
+/// enum Message {
+///     Ping,
+///     Readings([Reading; 2], Option<u8>),
+///     Reset { delay: u64 },
+/// }
+///
+/// let _: MaxSerialized<Reading> = Const::<6>;
+/// let _: MaxSerialized<Message> = Const::<15>;
+/// let buffer = [0_u8; Message::MAX_SIZE];
+/// assert_eq!(buffer.len(), 15);
+/// ```
+#[proc_macro_derive(MaxSerializedSize, attributes(max_size))]
+pub fn derive_max_serialized_size(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    layout::expand_max_size(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
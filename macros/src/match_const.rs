@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+    Expr, Ident, LitInt, Token,
+};
+
+use crate::range;
+
+/// `value { N @ 1 | 4..=8 => body, _ => fallback }`
+pub struct Input {
+    value: Expr,
+    arms: Vec<Arm>,
+}
+
+struct Arm {
+    param: Option<Ident>,
+    cases: Vec<core::ops::RangeInclusive<i64>>,
+    body: Expr,
+}
+
+fn int(input: ParseStream) -> syn::Result<i64> {
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let value: i64 = input.parse::<LitInt>()?.base10_parse()?;
+    Ok(if negative { -value } else { value })
+}
+
+/// `4`, `4..8` or `4..=8`
+fn case(input: ParseStream) -> syn::Result<core::ops::RangeInclusive<i64>> {
+    let start = int(input)?;
+    if input.parse::<Option<Token![..=]>>()?.is_some() {
+        Ok(start..=int(input)?)
+    } else if input.parse::<Option<Token![..]>>()?.is_some() {
+        Ok(start..=int(input)? - 1)
+    } else {
+        Ok(start..=start)
+    }
+}
+
+impl Parse for Arm {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let (param, cases) = if input.parse::<Option<Token![_]>>()?.is_some() {
+            (None, Vec::new())
+        } else {
+            let param = if input.peek(Ident) && input.peek2(Token![@]) {
+                let param = input.parse()?;
+                input.parse::<Token![@]>()?;
+                Some(param)
+            } else {
+                None
+            };
+            let mut cases = vec![case(input)?];
+            while input.parse::<Option<Token![|]>>()?.is_some() {
+                cases.push(case(input)?);
+            }
+            (param, cases)
+        };
+        input.parse::<Token![=>]>()?;
+        let body = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { param, cases, body })
+    }
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let value = input.call(Expr::parse_without_eager_brace)?;
+        let content;
+        braced!(content in input);
+        let mut arms = Vec::new();
+        while !content.is_empty() {
+            arms.push(content.parse()?);
+        }
+        Ok(Self { value, arms })
+    }
+}
+
+pub fn expand(Input { value, arms }: Input) -> TokenStream {
+    let arms = arms.into_iter().map(|Arm { param, cases, body }| match param {
+        // Every value gets its own arm with the type in scope
+        Some(param) => cases
+            .into_iter()
+            .flatten()
+            .map(|n| {
+                let pat = range::pattern(n);
+                let n = range::literal(n);
+                quote! {
+                    #pat => {
+                        #[allow(dead_code)]
+                        type #param = ::typenum_alias::Const<#n>;
+                        #body
+                    }
+                }
+            })
+            .collect(),
+        None if cases.is_empty() => quote!(_ => #body,),
+        None => {
+            let pats = cases.into_iter().map(|case| {
+                let (start, end) = (range::pattern(*case.start()), range::pattern(*case.end()));
+                quote!(#start..=#end)
+            });
+            quote!(#(#pats)|* => #body,)
+        }
+    });
+    quote! {
+        match #value {
+            #(#arms)*
+        }
+    }
+}
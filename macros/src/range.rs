@@ -0,0 +1,125 @@
+use proc_macro2::{Group, Ident, Literal, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, ExprLit, ExprRange, ExprUnary, Lit, RangeLimits, Token, UnOp,
+};
+
+/// `N in 1..=64`
+pub struct Args {
+    pub param: Ident,
+    pub range: core::ops::RangeInclusive<i64>,
+}
+
+fn int(expr: Option<&Expr>) -> syn::Result<i64> {
+    match expr {
+        Some(Expr::Lit(ExprLit { lit: Lit::Int(lit), .. })) => lit.base10_parse(),
+        Some(Expr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. })) => Ok(-int(Some(expr))?),
+        Some(expr) => Err(syn::Error::new_spanned(expr, "expected an integer literal")),
+        None => Err(syn::Error::new(Span::call_site(), "the range must be bounded")),
+    }
+}
+
+/// `1..=64` or `-8..8`
+pub fn parse(input: ParseStream) -> syn::Result<core::ops::RangeInclusive<i64>> {
+    let ExprRange { start, end, limits, .. } = match input.call(Expr::parse_without_eager_brace)? {
+        Expr::Range(range) => range,
+        expr => return Err(syn::Error::new_spanned(expr, "expected a range")),
+    };
+    let start = int(start.as_deref())?;
+    let end = int(end.as_deref())?;
+    Ok(match limits {
+        RangeLimits::HalfOpen(_) => start..=end - 1,
+        RangeLimits::Closed(_) => start..=end,
+    })
+}
+
+impl Parse for Args {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let param = input.parse()?;
+        input.parse::<Token![in]>()?;
+        Ok(Self { param, range: parse(input)? })
+    }
+}
+
+/// `value` as a const argument, negative values are wrapped in braces: `Const<{ -1 }>`
+pub fn literal(value: i64) -> TokenStream {
+    let lit = Literal::i64_unsuffixed(value.abs());
+    if value < 0 {
+        quote::quote!({ -#lit })
+    } else {
+        lit.into_token_stream()
+    }
+}
+
+/// `value` as an expression or a pattern: `-1`
+pub fn pattern(value: i64) -> TokenStream {
+    let lit = Literal::i64_unsuffixed(value.abs());
+    if value < 0 {
+        quote::quote!(-#lit)
+    } else {
+        lit.into_token_stream()
+    }
+}
+
+/// Tracks whether the tokens are inside `<...>`, where negative values need braces.
+/// Comparisons are counted too, which only adds braces to expressions, where they're harmless.
+fn angle_depth(depth: usize, tokens: &[TokenTree], i: usize) -> usize {
+    let punct = |i: usize| match tokens.get(i) {
+        Some(TokenTree::Punct(punct)) => Some(punct),
+        _ => None,
+    };
+    let Some(current) = punct(i) else { return depth };
+    let joint_with =
+        |c| current.spacing() == Spacing::Joint && punct(i + 1).is_some_and(|p| p.as_char() == c);
+    let after = |c| {
+        i.checked_sub(1)
+            .and_then(punct)
+            .is_some_and(|p| p.as_char() == c && p.spacing() == Spacing::Joint)
+    };
+    match current.as_char() {
+        '<' if !joint_with('=') => depth + 1,
+        '>' if after('=') => 0,
+        '>' if !after('-') && !joint_with('=') => depth.saturating_sub(1),
+        ';' => 0,
+        _ => depth,
+    }
+}
+
+/// Replaces every occurrence of `param` with `value`, & `ident~param` with a single identifier.
+/// Negative values are pasted like `typenum` names them, e. g. `kernel_~N` becomes `kernel_N4`,
+/// & are wrapped in braces only inside `<...>`, so they're valid in patterns too.
+pub fn substitute(tokens: TokenStream, param: &Ident, value: i64) -> TokenStream {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    let mut out = TokenStream::new();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < tokens.len() {
+        depth = angle_depth(depth, &tokens, i);
+        match (&tokens[i], tokens.get(i + 1), tokens.get(i + 2)) {
+            (
+                TokenTree::Ident(prefix),
+                Some(TokenTree::Punct(tilde)),
+                Some(TokenTree::Ident(ident)),
+            ) if tilde.as_char() == '~' && ident == param => {
+                let sign = if value < 0 { "N" } else { "" };
+                let pasted = Ident::new(&format!("{prefix}{sign}{}", value.abs()), prefix.span());
+                out.extend([TokenTree::Ident(pasted)]);
+                i += 3;
+                continue;
+            }
+            (TokenTree::Ident(ident), ..) if ident == param => {
+                out.extend(if depth > 0 { literal(value) } else { pattern(value) });
+            }
+            (TokenTree::Group(group), ..) => {
+                let mut substituted =
+                    Group::new(group.delimiter(), substitute(group.stream(), param, value));
+                substituted.set_span(group.span());
+                out.extend([TokenTree::Group(substituted)]);
+            }
+            (token, ..) => out.extend([token.clone()]),
+        }
+        i += 1;
+    }
+    out
+}
@@ -0,0 +1,68 @@
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use syn::{
+    braced,
+    parse::{Parse, ParseStream},
+};
+
+use crate::range::{substitute, Args};
+
+/// `N in 0..8 { ... }`
+pub struct Input {
+    args: Args,
+    body: TokenStream,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let args = input.parse()?;
+        let body;
+        braced!(body in input);
+        Ok(Self { args, body: body.parse()? })
+    }
+}
+
+/// Repeats the `#(...)*` sections of `tokens`, returns `None` if there are none
+fn sections(tokens: TokenStream, args: &Args) -> Option<TokenStream> {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    let mut out = TokenStream::new();
+    let mut found = false;
+    let mut i = 0;
+    while i < tokens.len() {
+        match (&tokens[i], tokens.get(i + 1), tokens.get(i + 2)) {
+            (
+                TokenTree::Punct(hash),
+                Some(TokenTree::Group(group)),
+                Some(TokenTree::Punct(star)),
+            ) if hash.as_char() == '#'
+                && group.delimiter() == Delimiter::Parenthesis
+                && star.as_char() == '*' =>
+            {
+                let section = group.stream();
+                out.extend(
+                    args.range.clone().map(|value| substitute(section.clone(), &args.param, value)),
+                );
+                found = true;
+                i += 3;
+                continue;
+            }
+            (TokenTree::Group(group), ..) => match sections(group.stream(), args) {
+                Some(stream) => {
+                    let mut repeated = Group::new(group.delimiter(), stream);
+                    repeated.set_span(group.span());
+                    out.extend([TokenTree::Group(repeated)]);
+                    found = true;
+                }
+                None => out.extend([TokenTree::Group(group.clone())]),
+            },
+            (token, ..) => out.extend([token.clone()]),
+        }
+        i += 1;
+    }
+    found.then_some(out)
+}
+
+pub fn expand(Input { args, body }: Input) -> TokenStream {
+    sections(body.clone(), &args).unwrap_or_else(|| {
+        args.range.clone().map(|value| substitute(body.clone(), &args.param, value)).collect()
+    })
+}
@@ -0,0 +1,48 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Token,
+};
+
+use crate::range::{self, Args};
+
+/// `value, N in 0..=16 => body`
+pub struct Input {
+    value: Expr,
+    args: Args,
+    body: Expr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let value = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let args = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let body = input.parse()?;
+        Ok(Self { value, args, body })
+    }
+}
+
+pub fn expand(Input { value, args: Args { param, range }, body }: Input) -> TokenStream {
+    let arms = range.map(|n| arm(&param, n, &body));
+    quote! {
+        match #value {
+            #(#arms)*
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+
+fn arm(param: &Ident, n: i64, body: &Expr) -> TokenStream {
+    let pat = range::pattern(n);
+    let n = range::literal(n);
+    quote! {
+        #pat => ::core::option::Option::Some({
+            #[allow(dead_code)]
+            type #param = ::typenum_alias::Const<#n>;
+            #body
+        }),
+    }
+}
@@ -0,0 +1,49 @@
+//! Nonce & tag sizes of `aead` as `Const`
+
+use crate::Constify;
+use aead::{AeadCore, Nonce, Tag};
+use hybrid_array::{Array, ArraySize};
+
+/// The nonce size of `A` as `Const`
+pub type NonceSize<A> = Constify<<A as AeadCore>::NonceSize>;
+
+/// The tag size of `A` as `Const`
+pub type TagSize<A> = Constify<<A as AeadCore>::TagSize>;
+
+/// The nonce of `A` from an array of its length, other lengths don't compile.
+///
+/// ```rust
+/// use aead::{AeadCore, TagPosition};
+/// use typenum_alias::{aead::{nonce, tag, NonceSize, TagSize}, hybrid_array::Size, Const};
+///
+/// struct Cipher;
+///
+/// impl AeadCore for Cipher {
+///     type NonceSize = Size<Const<12>>;
+///     type TagSize = Size<Const<16>>;
+///     const TAG_POSITION: TagPosition = TagPosition::Postfix;
+/// }
+///
+/// let _: (NonceSize<Cipher>, TagSize<Cipher>) = (Const::<12>, Const::<16>);
+/// let n = nonce::<Cipher, 12>([0; 12]);
+/// let t = tag::<Cipher, 16>([0; 16]);
+/// assert_eq!(n.len() + t.len(), 28);
+/// ```
+#[must_use]
+pub fn nonce<A, const N: usize>(bytes: [u8; N]) -> Nonce<A>
+where
+    A: AeadCore,
+    A::NonceSize: ArraySize<ArrayType<u8> = [u8; N]>,
+{
+    Array(bytes)
+}
+
+/// The tag of `A` from an array of its length, other lengths don't compile
+#[must_use]
+pub fn tag<A, const N: usize>(bytes: [u8; N]) -> Tag<A>
+where
+    A: AeadCore,
+    A::TagSize: ArraySize<ArrayType<u8> = [u8; N]>,
+{
+    Array(bytes)
+}
@@ -0,0 +1,31 @@
+/// Defines aliases to `Const<N>` for named constants, so that the names show up in the API instead of numbers.
+///
+/// Each definition creates two aliases: `Name`, which is `Const<N>`, & `NameTypenum`, which is its `typenum` representation.
+/// Doc comments & attributes are forwarded to both of them.
+///
+/// ```rust
+/// use typenum_alias::{alias, consts::P16, Const};
+///
+/// alias! {
+///     /// Size of a block in bytes
+///     pub BlockSize = 16;
+///     KeyLen = 8;
+/// }
+///
+/// let _: BlockSize = Const::<16>;
+/// let _: BlockSizeTypenum = P16::new();
+/// ```
+#[macro_export]
+macro_rules! alias {
+    ($($(#[$attr:meta])* $vis:vis $name:ident = $n:literal;)+) => {
+        $crate::__private::paste! {
+            $($(#[$attr])*
+            #[doc = ::core::concat!("\n\n`Const<", ::core::stringify!($n), ">`")]
+            $vis type $name = $crate::Const<$n>;
+
+            $(#[$attr])*
+            #[doc = ::core::concat!("\n\n`typenum` representation of [`", ::core::stringify!($name), "`]")]
+            $vis type [<$name Typenum>] = $crate::Typenum<$crate::Const<$n>>;)+
+        }
+    };
+}
@@ -0,0 +1,38 @@
+//! Drop-in replacement for [`typenum::consts`] with `Const<N>` names next to the `typenum` ones.
+//!
+//! [`typenum::consts`] is re-exported as is, so `use typenum::consts::*` can be swapped for `use typenum_alias::aliases::*`
+//! without breaking bounds like `N: Unsigned` or `N: ArrayLength`. `CN` is `Const<N>` & `CNN` is `Const<-N>`,
+//! they cover the enabled conversion table & convert to the `typenum` names with [`Typenum`](crate::Typenum).
+//!
+//! ```rust
+//! use typenum_alias::{aliases::*, Const, Typenum};
+//! use typenum::Unsigned;
+//!
+//! fn len<N: Unsigned>() -> usize {
+//!     N::USIZE
+//! }
+//!
+//! assert_eq!(len::<U8>(), 8);
+//! let _: C8 = Const::<8>;
+//! let _: CN3 = Const::<-3>;
+//! let _: Typenum<C8> = P8::new();
+//! ```
+
+use crate::Const;
+use paste::paste;
+
+#[doc(no_inline)]
+pub use typenum::consts::*;
+
+pub type C0 = Const<0>;
+
+macro_rules! define_aliases {
+    ($($num:literal),+) => {
+        paste! {
+            $(pub type [<C $num>] = Const<$num>;
+            pub type [<CN $num>] = Const<-$num>;)+
+        }
+    };
+}
+
+with_table!(define_aliases);
@@ -0,0 +1,257 @@
+//! Support of the assertion macros
+
+use crate::{Const, Normalize};
+use core::marker::PhantomData;
+use typenum::{Equal, Less};
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` isn't equal to `{Expected}`",
+    label = "assertion failed"
+)]
+pub trait ConstEq<Expected> {}
+
+impl<T> ConstEq<T> for T {}
+
+/// Never implemented, so that the compiler reports the value it's required for
+#[diagnostic::on_unimplemented(message = "the value is `{Self}`", label = "revealed here")]
+pub trait Reveal {}
+
+/// Fails to compile, showing the value of a type-level expression: `let _: Diagnose<Sum<N, M>>;`.
+///
+/// The expression must be concrete: in generic code the compiler can only show it unevaluated.
+/// See also [`reveal_const!`](crate::reveal_const).
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{Const, Diagnose};
+/// use typenum::Prod;
+///
+/// // error: the value is `Const<12>`
+/// let _: Diagnose<Prod<Const<3>, Const<4>>>;
+/// ```
+pub struct Diagnose<T: Normalize>(PhantomData<T>)
+where
+    T::Output: Reveal;
+
+/// The value of `Const<N>` in const contexts
+pub trait Value {
+    const VALUE: i32;
+}
+
+impl<const N: i32> Value for Const<N> {
+    const VALUE: i32 = N;
+}
+
+/// Implemented by the result of `Cmp<R> for L` if `L < R`
+#[diagnostic::on_unimplemented(message = "`{L}` isn't less than `{R}`", label = "assertion failed")]
+pub trait LessThan<L, R> {}
+
+impl<L, R> LessThan<L, R> for Less {}
+
+/// Implemented by the result of `Cmp<R> for L` if `L <= R`
+#[diagnostic::on_unimplemented(
+    message = "`{L}` isn't less than or equal to `{R}`",
+    label = "assertion failed"
+)]
+pub trait LessOrEqual<L, R> {}
+
+impl<L, R> LessOrEqual<L, R> for Less {}
+impl<L, R> LessOrEqual<L, R> for Equal {}
+
+/// Asserts at compile time that a type-level expression equals the expected constant:
+/// `const_test!(Sum<Typenum<Const<3>>, Typenum<Const<4>>> == Const<7>)`.
+///
+/// Both sides may be `Const<N>` or `typenum` integers, they are compared as `Const<N>`,
+/// so failures print `` `Const<8>` isn't equal to `Const<7>` ``. The expression can't use generic parameters.
+///
+/// ```rust
+/// use typenum_alias::{const_test, calc, Const, Typenum};
+/// use typenum::{Sum, U3, P7};
+///
+/// const_test!(Sum<Typenum<Const<3>>, Typenum<Const<4>>> == Const<7>);
+/// const_test!(calc!(Const<3> * 2 + 1) == P7);
+/// const_test!(U3 == Const<3>);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{const_test, Const};
+///
+/// const_test!(typenum::Sum<Const<3>, Const<4>> == Const<8>);
+/// ```
+#[macro_export]
+macro_rules! const_test {
+    (@split [$($l:tt)+] == $($r:tt)+) => {
+        const _: () = {
+            fn assert<L: $crate::__private::ConstEq<R>, R>() {}
+            let _ = assert::<
+                <$($l)+ as $crate::Normalize>::Output,
+                <$($r)+ as $crate::Normalize>::Output,
+            >;
+        };
+    };
+    (@split [$($l:tt)*] $t:tt $($r:tt)*) => {
+        $crate::const_test! {@split [$($l)* $t] $($r)*}
+    };
+    (@split $l:tt) => {
+        ::core::compile_error!("`const_test!` expects `expression == expected`")
+    };
+    ($($e:tt)+) => {
+        $crate::const_test! {@split [] $($e)+}
+    };
+}
+
+/// Asserts at compile time that two `Const` or `typenum` types are equal, printing both values on failure.
+///
+/// ```rust
+/// use typenum_alias::{assert_type_eq, Const};
+/// use typenum::Prod;
+///
+/// assert_type_eq!(Prod<Const<3>, Const<4>>, typenum::P12);
+/// ```
+#[macro_export]
+macro_rules! assert_type_eq {
+    ($l:ty, $r:ty $(,)?) => {
+        $crate::const_test!($l == $r);
+    };
+}
+
+/// Asserts at compile time that `L < R` for `Const` or `typenum` operands, printing both values on failure.
+///
+/// ```rust
+/// use typenum_alias::{assert_const_lt, Const};
+///
+/// assert_const_lt!(Const<-3>, typenum::U2);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{assert_const_lt, Const};
+///
+/// assert_const_lt!(Const<3>, Const<3>);
+/// ```
+#[macro_export]
+macro_rules! assert_const_lt {
+    ($l:ty, $r:ty $(,)?) => {
+        $crate::__assert_cmp!(LessThan, $l, $r);
+    };
+}
+
+/// Asserts at compile time that `L <= R` for `Const` or `typenum` operands, printing both values on failure.
+///
+/// ```rust
+/// use typenum_alias::{assert_const_le, Const};
+///
+/// assert_const_le!(Const<3>, Const<3>);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{assert_const_le, Const};
+///
+/// assert_const_le!(Const<4>, Const<3>);
+/// ```
+#[macro_export]
+macro_rules! assert_const_le {
+    ($l:ty, $r:ty $(,)?) => {
+        $crate::__assert_cmp!(LessOrEqual, $l, $r);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_cmp {
+    ($tr:ident, $l:ty, $r:ty) => {
+        const _: () = {
+            fn assert<L: $crate::type_operators::Cmp<R>, R>()
+            where
+                $crate::operator_aliases::Compare<L, R>: $crate::__private::$tr<L, R>,
+            {
+            }
+            let _ = assert::<<$l as $crate::Normalize>::Output, <$r as $crate::Normalize>::Output>;
+        };
+    };
+}
+
+/// Asserts at compile time that `size_of::<T>()` equals a `Const` or `typenum` integer, or is at most it with `<=`.
+///
+/// ```rust
+/// use typenum_alias::{assert_size_of, Const};
+///
+/// #[repr(C)]
+/// struct Header {
+///     id: u32,
+///     len: u16,
+///     flags: u16,
+/// }
+///
+/// assert_size_of!(Header, Const<8>);
+/// assert_size_of!(Header, <= typenum::U16);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{assert_size_of, Const};
+///
+/// assert_size_of!(u64, Const<4>);
+/// ```
+#[macro_export]
+macro_rules! assert_size_of {
+    ($t:ty, <= $n:ty $(,)?) => {
+        $crate::__assert_layout!(size_of, <=, "size of `", $t, "` exceeds `", $n);
+    };
+    ($t:ty, $n:ty $(,)?) => {
+        $crate::__assert_layout!(size_of, ==, "size of `", $t, "` isn't `", $n);
+    };
+}
+
+/// Asserts at compile time that `align_of::<T>()` equals a `Const` or `typenum` integer, or is at most it with `<=`.
+///
+/// ```rust
+/// use typenum_alias::{assert_align_of, Const};
+///
+/// assert_align_of!(u32, Const<4>);
+/// assert_align_of!(u8, <= Const<8>);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{assert_align_of, Const};
+///
+/// assert_align_of!(u64, <= Const<4>);
+/// ```
+#[macro_export]
+macro_rules! assert_align_of {
+    ($t:ty, <= $n:ty $(,)?) => {
+        $crate::__assert_layout!(align_of, <=, "alignment of `", $t, "` exceeds `", $n);
+    };
+    ($t:ty, $n:ty $(,)?) => {
+        $crate::__assert_layout!(align_of, ==, "alignment of `", $t, "` isn't `", $n);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_layout {
+    ($f:ident, $op:tt, $what:literal, $t:ty, $fail:literal, $n:ty) => {
+        const _: () = {
+            let expected = <<$n as $crate::Normalize>::Output as $crate::__private::Value>::VALUE;
+            ::core::assert!(
+                expected >= 0 && ::core::mem::$f::<$t>() $op expected as usize,
+                ::core::concat!($what, ::core::stringify!($t), $fail, ::core::stringify!($n), "`"),
+            );
+        };
+    };
+}
+
+/// Fails to compile, showing the value of a type-level expression: `reveal_const!(Sum<Const<3>, Const<4>>)`
+/// reports `` the value is `Const<7>` ``. [`Diagnose`](crate::Diagnose) does the same in type positions.
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{reveal_const, Const};
+/// use typenum::Sum;
+///
+/// reveal_const!(Sum<Const<3>, Const<4>>);
+/// ```
+#[macro_export]
+macro_rules! reveal_const {
+    ($t:ty $(,)?) => {
+        const _: () = {
+            let _: ::core::option::Option<$crate::Diagnose<$t>> = ::core::option::Option::None;
+        };
+    };
+}
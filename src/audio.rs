@@ -0,0 +1,70 @@
+//! Sizes of interleaved audio buffers as `Const`: samples, frames & bytes
+
+use crate::{size::RoundUp, Const};
+use typenum::{PartialQuot, Prod};
+
+/// A sample format of `Width` bytes per sample, see [`SampleWidth`]
+pub trait SampleFormat {
+    type Width;
+}
+
+/// The bytes per sample of the format `S` as `Const`
+pub type SampleWidth<S> = <S as SampleFormat>::Width;
+
+macro_rules! formats {
+    ($($(#[$attr:meta])* $format:ident => $width:literal;)+) => {
+        $($(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $format;
+
+        impl SampleFormat for $format {
+            type Width = Const<$width>;
+        })+
+    };
+}
+
+formats! {
+    /// Unsigned 8-bit PCM
+    U8 => 1;
+    /// Signed 16-bit PCM
+    I16 => 2;
+    /// Signed 24-bit PCM, packed
+    I24 => 3;
+    /// Signed 32-bit PCM
+    I32 => 4;
+    /// 32-bit float
+    F32 => 4;
+    /// 64-bit float
+    F64 => 8;
+}
+
+/// The number of samples of `Frames` interleaved frames of `Channels`
+///
+/// ```rust
+/// use typenum_alias::{
+///     audio::{BlockAlign, BlockFrames, BytesToSamples, Interleaved, SampleBytes, F32, I16, I24},
+///     Const,
+/// };
+///
+/// let _: Interleaved<Const<8>, Const<2>> = Const::<16>;
+/// let _: BlockAlign<Const<2>, I16> = Const::<4>;
+/// let _: SampleBytes<Const<4>, F32> = Const::<16>;
+/// let _: BytesToSamples<Const<12>, I24> = Const::<4>;
+/// let _: BlockFrames<Const<10>, Const<4>> = Const::<12>;
+/// ```
+pub type Interleaved<Frames, Channels> = Prod<Frames, Channels>;
+
+/// The bytes of a frame of `Channels` samples of the format `S`, `nBlockAlign` of WAVE
+pub type BlockAlign<Channels, S> = Prod<Channels, SampleWidth<S>>;
+
+/// The bytes of `Samples` samples of the format `S`
+pub type SampleBytes<Samples, S> = Prod<Samples, SampleWidth<S>>;
+
+/// The number of samples in `Bytes` bytes of the format `S`, other numbers of bytes don't compile
+pub type BytesToSamples<Bytes, S> = PartialQuot<Bytes, SampleWidth<S>>;
+
+/// The bytes of `Frames` interleaved frames of `Channels` samples of the format `S`
+pub type FrameBytes<Frames, Channels, S> = Prod<Frames, BlockAlign<Channels, S>>;
+
+/// `Frames` rounded up to a multiple of the processing block of `Block` frames
+pub type BlockFrames<Frames, Block> = RoundUp<Frames, Block>;
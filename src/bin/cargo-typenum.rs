@@ -0,0 +1,44 @@
+//! Rewrites `typenum` types in the output of `cargo` to `Const<N>` & `U<N>`.
+//!
+//! `cargo typenum build` runs `cargo build`, without arguments it filters the standard input:
+//! `cargo build 2>&1 | cargo-typenum`.
+
+#![deny(clippy::pedantic)]
+
+use std::{
+    env,
+    io::{self, BufRead, BufReader, Write},
+    process::{self, Command, Stdio},
+    thread,
+};
+use typenum_alias::normalize::rewrite;
+
+fn filter(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    for line in input.lines() {
+        writeln!(output, "{}", rewrite(&line?))?;
+    }
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let mut args = env::args_os().skip(1).peekable();
+    // `cargo typenum` passes the name of the subcommand first
+    if args.peek().is_some_and(|arg| arg == "typenum") {
+        args.next();
+    }
+    let args: Vec<_> = args.collect();
+    if args.is_empty() {
+        return filter(io::stdin().lock(), io::stdout().lock());
+    }
+
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut child =
+        Command::new(cargo).args(args).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+    let (Some(stdout), Some(stderr)) = (child.stdout.take(), child.stderr.take()) else {
+        unreachable!("both outputs are piped");
+    };
+    let stdout = thread::spawn(move || filter(BufReader::new(stdout), io::stdout()));
+    filter(BufReader::new(stderr), io::stderr())?;
+    stdout.join().unwrap_or_else(|_| Err(io::Error::other("failed to filter stdout")))?;
+    process::exit(child.wait()?.code().unwrap_or(1));
+}
@@ -0,0 +1,50 @@
+//! [`Bitmap`](bitmaps::Bitmap) of `bitmaps` with `Const` sizes: `Bitmap<64>` instead of `Bitmap<U64>`
+
+use crate::{ops::Magnitude, ops::ToUnsigned, Const, Constify, Normalize, ToTypenum, Typenum};
+use bitmaps::Bits;
+
+/// Converts `Const<N>` to the `typenum` size of [`Bitmap`](bitmaps::Bitmap).
+/// Generic code needs only this bound: `fn f<const N: i32>(b: Bitmap<N>) where Const<N>: ToBits`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` isn't a valid size of `bitmaps::Bitmap`",
+    label = "expected a `Const` in `1..=1024` from the conversion table"
+)]
+pub trait ToBits {
+    type Output: Bits;
+}
+
+pub type Size<N> = <N as ToBits>::Output;
+
+impl<const N: i32> ToBits for Const<N>
+where
+    Const<N>: ToTypenum,
+    Typenum<Const<N>>: ToUnsigned,
+    Magnitude<Typenum<Const<N>>>: Bits,
+{
+    type Output = Magnitude<Typenum<Const<N>>>;
+}
+
+/// `Bitmap` of `N` bits. Sizes above 16 need a bigger conversion table, see the `consts-*` features.
+///
+/// ```rust
+/// use typenum_alias::{bitmaps::{Bitmap, Len}, Const};
+///
+/// let mut free = Bitmap::<12>::mask(8);
+/// free.set(10, true);
+///
+/// let _: Len<Bitmap<12>> = Const::<12>;
+/// assert_eq!(free.len(), 9);
+/// ```
+pub type Bitmap<const N: i32> = bitmaps::Bitmap<Size<Const<N>>>;
+
+/// The size of a `Bitmap` as `Const`, see [`Len`]
+pub trait BitmapLen {
+    type Output;
+}
+
+/// The size of a `Bitmap` as `Const`: `Len<bitmaps::Bitmap<U64>>` is `Const<64>`
+pub type Len<B> = <B as BitmapLen>::Output;
+
+impl<S: Bits + Normalize> BitmapLen for bitmaps::Bitmap<S> {
+    type Output = Constify<S>;
+}
@@ -0,0 +1,247 @@
+//! Unsigned integers of arbitrary bit width, which is stored in the type as `Const<W>`.
+//!
+//! Arithmetic widens the result, so it never overflows: `UBits<Const<L>> + UBits<Const<R>>` is `UBits<Const<{ max(L, R) + 1 }>>`
+//! and `UBits<Const<L>> * UBits<Const<R>>` is `UBits<Const<{ L + R }>>`.
+//!
+//! The widths of the results are computed by `typenum`, so they have to be in the conversion table.
+//! The feature `bits` enables `consts-256`, which fits the widths of sums & products of any `UBits`.
+
+use crate::Const;
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Add, Mul},
+};
+use typenum::{IsLessOrEqual, Max, Maximum, Sum, B1};
+
+/// An unsigned integer of `W` bits
+///
+/// ```rust
+/// use typenum_alias::{bits::UBits, Const};
+///
+/// let a = UBits::<Const<8>>::MAX;
+/// let b = UBits::<Const<4>>::new(3).unwrap();
+///
+/// let sum: UBits<Const<9>> = a + b;
+/// let product: UBits<Const<12>> = a * b;
+/// assert_eq!((sum.get(), product.get()), (258, 765));
+/// assert_eq!(UBits::<Const<9>>::BITS, 9);
+///
+/// // Values are checked or wrapped at the width, up to 128 bits
+/// assert!(UBits::<Const<4>>::new(16).is_none());
+/// assert_eq!(UBits::<Const<4>>::wrapping(17).get(), 1);
+/// assert_eq!(UBits::<Const<128>>::MAX.get(), u128::MAX);
+/// assert_eq!(UBits::<Const<128>>::wrapping(u128::MAX).get(), u128::MAX);
+///
+/// // Registers of 16 & 32 bits
+/// let wide: UBits<Const<17>> = UBits::<Const<16>>::MAX + UBits::<Const<16>>::MAX;
+/// assert_eq!(wide.get(), 0x1_fffe);
+/// let wide: UBits<Const<64>> = UBits::<Const<32>>::MAX * UBits::<Const<32>>::MAX;
+/// assert_eq!(wide.get(), u128::from(u32::MAX) * u128::from(u32::MAX));
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{bits::UBits, Const};
+///
+/// // error: `UBits` width must be in range 0..=128
+/// let _ = UBits::<Const<129>>::BITS;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{bits::UBits, Const};
+///
+/// // error: product of `UBits` is wider than 128 bits
+/// let _ = UBits::<Const<128>>::MAX * UBits::<Const<1>>::MAX;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{bits::UBits, Const};
+///
+/// // error: sum of `UBits` is wider than 128 bits
+/// let _ = UBits::<Const<128>>::MAX + UBits::<Const<128>>::MAX;
+/// ```
+pub struct UBits<W> {
+    value: u128,
+    width: PhantomData<W>,
+}
+
+impl<W> UBits<W> {
+    const fn from_raw(value: u128) -> Self {
+        Self { value, width: PhantomData }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> u128 {
+        self.value
+    }
+}
+
+impl<const W: i32> UBits<Const<W>> {
+    pub const BITS: u32 = {
+        assert!(W >= 0 && W <= 128, "`UBits` width must be in range 0..=128");
+        W.unsigned_abs()
+    };
+
+    pub const MAX: Self = Self::from_raw(match Self::BITS {
+        0 => 0,
+        bits => u128::MAX >> (u128::BITS - bits),
+    });
+
+    pub const MIN: Self = Self::from_raw(0);
+
+    /// Returns `None` if `value` doesn't fit into `W` bits
+    #[inline]
+    #[must_use]
+    pub const fn new(value: u128) -> Option<Self> {
+        if value <= Self::MAX.value {
+            Some(Self::from_raw(value))
+        } else {
+            None
+        }
+    }
+
+    /// Discards the bits of `value` which don't fit into `W` bits
+    #[inline]
+    #[must_use]
+    pub const fn wrapping(value: u128) -> Self {
+        Self::from_raw(value & Self::MAX.value)
+    }
+
+    /// Converts to a type of the same or bigger width
+    ///
+    /// ```rust
+    /// use typenum_alias::{bits::UBits, Const};
+    ///
+    /// let wide: UBits<Const<32>> = UBits::<Const<8>>::MAX.widen();
+    /// assert_eq!(wide.get(), 255);
+    /// let same: UBits<Const<8>> = UBits::<Const<8>>::MAX.widen();
+    /// assert_eq!(same, UBits::MAX);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use typenum_alias::{bits::UBits, Const};
+    ///
+    /// // error: type mismatch resolving `<Const<8> as IsLessOrEqualPrivate<Const<4>, Greater>>::Output == B1`
+    /// let _: UBits<Const<4>> = UBits::<Const<8>>::MAX.widen();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn widen<const V: i32>(self) -> UBits<Const<V>>
+    where
+        Const<W>: IsLessOrEqual<Const<V>, Output = B1>,
+    {
+        UBits::from_raw(self.value)
+    }
+
+    /// Converts to a type of any width, discarding the bits which don't fit
+    ///
+    /// ```rust
+    /// use typenum_alias::{bits::UBits, Const};
+    ///
+    /// let value = UBits::<Const<16>>::new(0x1234).unwrap();
+    /// assert_eq!(value.truncate::<8>().get(), 0x34);
+    /// assert_eq!(value.truncate::<32>().get(), 0x1234);
+    /// assert_eq!(value.truncate::<0>().get(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn truncate<const V: i32>(self) -> UBits<Const<V>> {
+        UBits::wrapping(self.value)
+    }
+}
+
+impl<const L: i32, const R: i32> Add<UBits<Const<R>>> for UBits<Const<L>>
+where
+    Const<L>: Max<Const<R>>,
+    Maximum<Const<L>, Const<R>>: Add<Const<1>>,
+{
+    type Output = UBits<Sum<Maximum<Const<L>, Const<R>>, Const<1>>>;
+
+    #[inline]
+    fn add(self, rhs: UBits<Const<R>>) -> Self::Output {
+        const { assert!(L < 128 && R < 128, "sum of `UBits` is wider than 128 bits") };
+        UBits::from_raw(self.value + rhs.value)
+    }
+}
+
+impl<const L: i32, const R: i32> Mul<UBits<Const<R>>> for UBits<Const<L>>
+where
+    Const<L>: Add<Const<R>>,
+{
+    type Output = UBits<Sum<Const<L>, Const<R>>>;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: UBits<Const<R>>) -> Self::Output {
+        const { assert!(L + R <= 128, "product of `UBits` is wider than 128 bits") };
+        UBits::from_raw(self.value * rhs.value)
+    }
+}
+
+impl<W> Clone for UBits<W> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<W> Copy for UBits<W> {}
+
+impl<W> Default for UBits<W> {
+    #[inline]
+    fn default() -> Self {
+        Self::from_raw(0)
+    }
+}
+
+impl<W> PartialEq for UBits<W> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<W> Eq for UBits<W> {}
+
+impl<W> PartialOrd for UBits<W> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W> Ord for UBits<W> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<W> Hash for UBits<W> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<const W: i32> fmt::Debug for UBits<Const<W>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UBits<{W}>({})", self.value)
+    }
+}
+
+impl<const W: i32> fmt::Display for UBits<Const<W>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<W> From<UBits<W>> for u128 {
+    #[inline]
+    fn from(bits: UBits<W>) -> Self {
+        bits.value
+    }
+}
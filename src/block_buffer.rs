@@ -0,0 +1,87 @@
+//! [`BlockBuffer`] of `block-buffer` with `Const` block sizes & padded lengths of `block-padding` as `Const`
+
+use crate::{
+    hybrid_array::Size,
+    ops::{CeilQuot, DivCeil},
+    Const, Constify, Normalize,
+};
+use block_buffer::{BlockBuffer, BlockSizes, BufferKind, EagerBuffer, LazyBuffer};
+use block_padding::{AnsiX923, Iso10126, Iso7816, NoPadding, Pkcs7, ZeroPadding};
+use core::ops::{Add, Div, Mul, Rem};
+use typenum::{Prod, Quot, Sum};
+
+/// `EagerBuffer` with blocks of `N` bytes
+///
+/// ```rust
+/// use typenum_alias::{block_buffer::{BufferSize, EagerBuf}, Const};
+///
+/// let mut buffer = EagerBuf::<64>::default();
+/// let mut blocks = 0;
+/// buffer.digest_blocks(&[0; 100], |b| blocks += b.len());
+///
+/// assert_eq!((blocks, buffer.get_pos()), (1, 36));
+/// let _: BufferSize<EagerBuf<64>> = Const::<64>;
+/// ```
+pub type EagerBuf<const N: i32> = EagerBuffer<Size<Const<N>>>;
+
+/// `LazyBuffer` with blocks of `N` bytes
+pub type LazyBuf<const N: i32> = LazyBuffer<Size<Const<N>>>;
+
+/// The block size of a [`BlockBuffer`] as `Const`, see [`BufferSize`]
+pub trait ToBufferSize {
+    type Output;
+}
+
+pub type BufferSize<B> = <B as ToBufferSize>::Output;
+
+impl<BS: BlockSizes + Normalize, K: BufferKind> ToBufferSize for BlockBuffer<BS, K> {
+    type Output = Constify<BS>;
+}
+
+/// The length of a message of `Len` bytes, padded to blocks of `Bs` bytes by `Self`, see [`PaddedLen`].
+///
+/// `Pkcs7`, `Iso7816`, `AnsiX923` & `Iso10126` always append at least one byte, so a message of whole blocks
+/// grows by a block. `ZeroPadding` rounds up to whole blocks. `NoPadding` keeps the length, which must be whole blocks.
+pub trait PadLen<Len, Bs> {
+    type Output;
+}
+
+/// The length of a message of `Len` bytes after padding by `P` to blocks of `Bs` bytes
+///
+/// ```rust
+/// use block_padding::{Iso7816, NoPadding, Pkcs7, ZeroPadding};
+/// use typenum_alias::{block_buffer::PaddedLen, Const};
+///
+/// let _: PaddedLen<Pkcs7, Const<13>, Const<16>> = Const::<16>;
+/// let _: PaddedLen<Iso7816, Const<32>, Const<16>> = Const::<48>;
+/// let _: PaddedLen<ZeroPadding, Const<32>, Const<16>> = Const::<32>;
+/// let _: PaddedLen<ZeroPadding, Const<33>, Const<16>> = Const::<48>;
+/// let _: PaddedLen<NoPadding, Const<32>, Const<16>> = Const::<32>;
+/// ```
+pub type PaddedLen<P, Len, Bs> = <P as PadLen<Len, Bs>>::Output;
+
+macro_rules! impl_pad_len {
+    ($($padding:ty),+) => {
+        $(impl<Len, Bs> PadLen<Len, Bs> for $padding
+        where
+            Len: Div<Bs>,
+            Quot<Len, Bs>: Add<Const<1>>,
+            Sum<Quot<Len, Bs>, Const<1>>: Mul<Bs>,
+        {
+            type Output = Prod<Sum<Quot<Len, Bs>, Const<1>>, Bs>;
+        })+
+    };
+}
+
+impl_pad_len!(Pkcs7, Iso7816, AnsiX923, Iso10126);
+
+impl<Len: DivCeil<Bs>, Bs> PadLen<Len, Bs> for ZeroPadding
+where
+    CeilQuot<Len, Bs>: Mul<Bs>,
+{
+    type Output = Prod<CeilQuot<Len, Bs>, Bs>;
+}
+
+impl<Len: Rem<Bs, Output = Const<0>>, Bs> PadLen<Len, Bs> for NoPadding {
+    type Output = Len;
+}
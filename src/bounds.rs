@@ -0,0 +1,192 @@
+/// Generates the where-clause of an item from arithmetic relations between types.
+///
+/// The relations go first, separated by commas & terminated with `;`. Each one compares two expressions
+/// with `=`, `==`, `!=`, `<`, `<=`, `>` or `>=`. Expressions are written like in [`calc!`](crate::calc),
+/// but operands other than identifiers & literals have to be parenthesized, e. g. `(Const<16>)`.
+/// `A + B = R` binds `R` to the result, i. e. expands to `A: Add<B, Output = R>`. Comparisons require
+/// the result to be `True`. Bounds of all the intermediate results are generated too.
+///
+/// The bounds are prepended to the where-clause of the item following the relations, or create one.
+///
+/// ```rust
+/// use typenum_alias::{const_bounds, Const};
+///
+/// const_bounds! {
+///     where N + M = R, R * 2 <= (Const<16>);
+///     fn concat<N, M, R: Default>(_: N, _: M) -> R {
+///         R::default()
+///     }
+/// }
+///
+/// let _: Const<7> = concat(Const::<3>, Const::<4>);
+/// ```
+#[macro_export]
+macro_rules! const_bounds {
+    // Splits relations at commas
+    (@split [$($p:tt)*] [] ; $($item:tt)*) => {
+        $crate::const_bounds! {@rels [] [$($p)*] {$($item)*}}
+    };
+    (@split [$($p:tt)*] [$($c:tt)+] ; $($item:tt)*) => {
+        $crate::const_bounds! {@rels [] [$($p)* ($($c)+)] {$($item)*}}
+    };
+    (@split [$($p:tt)*] [$($c:tt)+] , $($r:tt)*) => {
+        $crate::const_bounds! {@split [$($p)* ($($c)+)] [] $($r)*}
+    };
+    (@split $p:tt [$($c:tt)*] $t:tt $($r:tt)*) => {
+        $crate::const_bounds! {@split $p [$($c)* $t] $($r)*}
+    };
+
+    // Finds the relation & folds both sides, carrying the rest in `{relation, rhs, k}`
+    (@rels $o:tt [] {$($item:tt)*}) => {
+        $crate::const_bounds! {@item $o [] $($item)*}
+    };
+    (@rels $o:tt [($($p:tt)*) $($ps:tt)*] $item:tt) => {
+        $crate::const_bounds! {@rel $o [] ($($p)*) {[$($ps)*] $item}}
+    };
+    (@rel $o:tt [$($l:tt)+] (= $($r:tt)+) $k:tt) => {
+        $crate::const_bounds! {@side $o {lhs = [$($r)+] $k} $($l)+}
+    };
+    (@rel $o:tt [$($l:tt)+] (== $($r:tt)+) $k:tt) => {
+        $crate::const_bounds! {@side $o {lhs == [$($r)+] $k} $($l)+}
+    };
+    (@rel $o:tt [$($l:tt)+] (!= $($r:tt)+) $k:tt) => {
+        $crate::const_bounds! {@side $o {lhs != [$($r)+] $k} $($l)+}
+    };
+    (@rel $o:tt [$($l:tt)+] (< $($r:tt)+) $k:tt) => {
+        $crate::const_bounds! {@side $o {lhs < [$($r)+] $k} $($l)+}
+    };
+    (@rel $o:tt [$($l:tt)+] (<= $($r:tt)+) $k:tt) => {
+        $crate::const_bounds! {@side $o {lhs <= [$($r)+] $k} $($l)+}
+    };
+    (@rel $o:tt [$($l:tt)+] (> $($r:tt)+) $k:tt) => {
+        $crate::const_bounds! {@side $o {lhs > [$($r)+] $k} $($l)+}
+    };
+    (@rel $o:tt [$($l:tt)+] (>= $($r:tt)+) $k:tt) => {
+        $crate::const_bounds! {@side $o {lhs >= [$($r)+] $k} $($l)+}
+    };
+    (@rel $o:tt [$($l:tt)*] ($t:tt $($r:tt)*) $k:tt) => {
+        $crate::const_bounds! {@rel $o [$($l)* $t] ($($r)*) $k}
+    };
+    (@rel $o:tt $l:tt () $k:tt) => {
+        ::core::compile_error!("`const_bounds!` expects a relation: `=`, `==`, `!=`, `<`, `<=`, `>` or `>=`")
+    };
+
+    // Folds an expression like `calc!`. Values are `{[type] [bounded type] [bound] [bound with `Output =`]}`,
+    // the bound of each value is emitted when it becomes an operand
+    (@side $o:tt $k:tt $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@fold $o $k [] {
+            [$crate::calc!(@operand [$x])]
+            [$crate::calc!(@operand [$x])]
+            []
+            [$crate::type_operators::Same<$crate::calc!(@operand [$x]), Output =]
+        } $($r)*}
+    };
+    (@fold $o:tt $k:tt $s:tt $t:tt * $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@mul $o $k $s $t (Prod Mul) $x $($r)*}
+    };
+    (@fold $o:tt $k:tt $s:tt $t:tt / $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@mul $o $k $s $t (Quot Div) $x $($r)*}
+    };
+    (@fold $o:tt $k:tt $s:tt $t:tt % $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@mul $o $k $s $t (Mod Rem) $x $($r)*}
+    };
+    (@fold $o:tt $k:tt $s:tt $t:tt + $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@add $o $k $s $t (Sum Add) $x $($r)*}
+    };
+    (@fold $o:tt $k:tt $s:tt $t:tt - $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@add $o $k $s $t (Diff Sub) $x $($r)*}
+    };
+    (@fold [$($o:tt)*] $k:tt [] $t:tt) => {
+        $crate::const_bounds! {@done [$($o)*] $k $t}
+    };
+    (@fold [$($o:tt)*] $k:tt [$f:tt {[$($s:tt)*] [$($sa:tt)*] [$($sb:tt)*] $sp:tt}] {[$($t:tt)*] [$($ta:tt)*] [$($tb:tt)*] $tp:tt}) => {
+        $crate::const_bounds! {@node [$($o)* $($sa)*: $($sb)*, $($ta)*: $($tb)*,] $k $f [$($s)*] [$($t)*] (@done)}
+    };
+    (@mul [$($o:tt)*] $k:tt $s:tt {[$($t:tt)*] [$($ta:tt)*] [$($tb:tt)*] $tp:tt} $f:tt $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@node [$($o)* $($ta)*: $($tb)*,] $k $f [$($t)*] [$crate::calc!(@operand [$x])] (@term $s) $($r)*}
+    };
+    (@add $o:tt $k:tt [] $t:tt $f:tt $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@fold $o $k [$f $t] {
+            [$crate::calc!(@operand [$x])]
+            [$crate::calc!(@operand [$x])]
+            []
+            [$crate::type_operators::Same<$crate::calc!(@operand [$x]), Output =]
+        } $($r)*}
+    };
+    (@add [$($o:tt)*] $k:tt [$g:tt {[$($s:tt)*] [$($sa:tt)*] [$($sb:tt)*] $sp:tt}] {[$($t:tt)*] [$($ta:tt)*] [$($tb:tt)*] $tp:tt} $f:tt $x:tt $($r:tt)*) => {
+        $crate::const_bounds! {@node [$($o)* $($sa)*: $($sb)*, $($ta)*: $($tb)*,] $k $g [$($s)*] [$($t)*] (@sum $f $x) $($r)*}
+    };
+    // Builds the value of `A op B` & passes it to the continuation
+    (@node $o:tt $k:tt ($alias:ident $tr:ident) [$($a:tt)*] [$($b:tt)*] $c:tt $($r:tt)*) => {
+        $crate::const_bounds! {@cont $o $k {
+            [$crate::operator_aliases::$alias<$($a)*, $($b)*>]
+            [$($a)*]
+            [::core::ops::$tr<$($b)*>]
+            [::core::ops::$tr<$($b)*, Output =]
+        } $c $($r)*}
+    };
+    (@cont $o:tt $k:tt $v:tt (@term $s:tt) $($r:tt)*) => {
+        $crate::const_bounds! {@fold $o $k $s $v $($r)*}
+    };
+    (@cont $o:tt $k:tt $v:tt (@sum $f:tt $x:tt) $($r:tt)*) => {
+        $crate::const_bounds! {@add $o $k [] $v $f $x $($r)*}
+    };
+    (@cont $o:tt $k:tt $v:tt (@done)) => {
+        $crate::const_bounds! {@done $o $k $v}
+    };
+
+    // Emits the relation between both sides
+    (@done $o:tt {lhs $rel:tt [$($r:tt)*] $k:tt} $l:tt) => {
+        $crate::const_bounds! {@side $o {rhs $rel $l $k} $($r)*}
+    };
+    (@done [$($o:tt)*] {rhs = {$lt:tt [$($la:tt)*] $lb:tt [$($lp:tt)*]} $k:tt} {[$($r:tt)*] [$($ra:tt)*] [$($rb:tt)*] $rp:tt}) => {
+        $crate::const_bounds! {@next [$($o)* $($la)*: $($lp)* $($r)*>, $($ra)*: $($rb)*,] $k}
+    };
+    (@done $o:tt {rhs == $l:tt $k:tt} $r:tt) => {
+        $crate::const_bounds! {@cmp $o IsEqual $l $r $k}
+    };
+    (@done $o:tt {rhs != $l:tt $k:tt} $r:tt) => {
+        $crate::const_bounds! {@cmp $o IsNotEqual $l $r $k}
+    };
+    (@done $o:tt {rhs < $l:tt $k:tt} $r:tt) => {
+        $crate::const_bounds! {@cmp $o IsLess $l $r $k}
+    };
+    (@done $o:tt {rhs <= $l:tt $k:tt} $r:tt) => {
+        $crate::const_bounds! {@cmp $o IsLessOrEqual $l $r $k}
+    };
+    (@done $o:tt {rhs > $l:tt $k:tt} $r:tt) => {
+        $crate::const_bounds! {@cmp $o IsGreater $l $r $k}
+    };
+    (@done $o:tt {rhs >= $l:tt $k:tt} $r:tt) => {
+        $crate::const_bounds! {@cmp $o IsGreaterOrEqual $l $r $k}
+    };
+    (@cmp [$($o:tt)*] $tr:ident {[$($l:tt)*] [$($la:tt)*] [$($lb:tt)*] $lp:tt} {[$($r:tt)*] [$($ra:tt)*] [$($rb:tt)*] $rp:tt} $k:tt) => {
+        $crate::const_bounds! {@next [
+            $($o)*
+            $($la)*: $($lb)*,
+            $($ra)*: $($rb)*,
+            $($l)*: $crate::type_operators::$tr<$($r)*, Output = $crate::consts::True>,
+        ] $k}
+    };
+    (@next $o:tt {$ps:tt $item:tt}) => {
+        $crate::const_bounds! {@rels $o $ps $item}
+    };
+
+    // Puts the bounds into the where-clause of the item
+    (@item [$($o:tt)*] [$($i:tt)*] where $($r:tt)*) => {
+        $($i)* where $($o)* $($r)*
+    };
+    (@item [$($o:tt)*] [$($i:tt)*] {$($body:tt)*}) => {
+        $($i)* where $($o)* {$($body)*}
+    };
+    (@item [$($o:tt)*] [$($i:tt)*] ;) => {
+        $($i)* where $($o)* ;
+    };
+    (@item $o:tt [$($i:tt)*] $t:tt $($r:tt)*) => {
+        $crate::const_bounds! {@item $o [$($i)* $t] $($r)*}
+    };
+
+    (where $($r:tt)+) => {
+        $crate::const_bounds! {@split [] [] $($r)+}
+    };
+}
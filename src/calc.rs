@@ -0,0 +1,101 @@
+/// Writes type-level arithmetic as an expression with the usual precedence of `+`, `-`, `*`, `/` & `%`.
+///
+/// Operands are types (e. g. `Const<3>` or generic parameters), integer literals, which become `Const<N>`,
+/// and parenthesized subexpressions. The expression expands to the nested operator aliases:
+/// `calc!(N * 2 + 1)` is `Sum<Prod<N, Const<2>>, Const<1>>`.
+///
+/// ```rust
+/// use typenum_alias::{calc, Const};
+///
+/// let _: calc!(Const<3> * 5 + 1) = Const::<16>;
+/// let _: calc!((Const<3> + 5) % 3 - -4) = Const::<6>;
+/// ```
+#[macro_export]
+macro_rules! calc {
+    // Groups the tokens of each operand, so that `Const<-1>` stays a single operand
+    (@tok [$($o:tt)*] [$($c:tt)+] []) => {
+        $crate::calc!(@start $($o)* [$($c)+])
+    };
+    (@tok [$($o:tt)*] [] [] - $($r:tt)*) => {
+        $crate::calc!(@tok [$($o)*] [-] [] $($r)*)
+    };
+    (@tok [$($o:tt)*] [$($c:tt)+] [] + $($r:tt)*) => {
+        $crate::calc!(@tok [$($o)* [$($c)+] +] [] [] $($r)*)
+    };
+    (@tok [$($o:tt)*] [$($c:tt)+] [] - $($r:tt)*) => {
+        $crate::calc!(@tok [$($o)* [$($c)+] -] [] [] $($r)*)
+    };
+    (@tok [$($o:tt)*] [$($c:tt)+] [] * $($r:tt)*) => {
+        $crate::calc!(@tok [$($o)* [$($c)+] *] [] [] $($r)*)
+    };
+    (@tok [$($o:tt)*] [$($c:tt)+] [] / $($r:tt)*) => {
+        $crate::calc!(@tok [$($o)* [$($c)+] /] [] [] $($r)*)
+    };
+    (@tok [$($o:tt)*] [$($c:tt)+] [] % $($r:tt)*) => {
+        $crate::calc!(@tok [$($o)* [$($c)+] %] [] [] $($r)*)
+    };
+    (@tok $o:tt [$($c:tt)*] [$($d:tt)*] < $($r:tt)*) => {
+        $crate::calc!(@tok $o [$($c)* <] [< $($d)*] $($r)*)
+    };
+    (@tok $o:tt [$($c:tt)*] [< $($d:tt)*] > $($r:tt)*) => {
+        $crate::calc!(@tok $o [$($c)* >] [$($d)*] $($r)*)
+    };
+    (@tok $o:tt [$($c:tt)*] [< < $($d:tt)*] >> $($r:tt)*) => {
+        $crate::calc!(@tok $o [$($c)* >>] [$($d)*] $($r)*)
+    };
+    (@tok $o:tt [$($c:tt)*] $d:tt $t:tt $($r:tt)*) => {
+        $crate::calc!(@tok $o [$($c)* $t] $d $($r)*)
+    };
+    (@tok $o:tt $c:tt $d:tt) => {
+        ::core::compile_error!("unbalanced `<` in `calc!` expression")
+    };
+
+    // Folds the grouped operands, `@fold [pending additive op, sum] [product] ...`
+    (@start $x:tt $($r:tt)*) => {
+        $crate::calc!(@fold [] [$crate::calc!(@operand $x)] $($r)*)
+    };
+    (@fold $s:tt [$($t:tt)*] * $x:tt $($r:tt)*) => {
+        $crate::calc!(@fold $s [$crate::operator_aliases::Prod<$($t)*, $crate::calc!(@operand $x)>] $($r)*)
+    };
+    (@fold $s:tt [$($t:tt)*] / $x:tt $($r:tt)*) => {
+        $crate::calc!(@fold $s [$crate::operator_aliases::Quot<$($t)*, $crate::calc!(@operand $x)>] $($r)*)
+    };
+    (@fold $s:tt [$($t:tt)*] % $x:tt $($r:tt)*) => {
+        $crate::calc!(@fold $s [$crate::operator_aliases::Mod<$($t)*, $crate::calc!(@operand $x)>] $($r)*)
+    };
+    (@fold [] [$($t:tt)*] + $x:tt $($r:tt)*) => {
+        $crate::calc!(@fold [Sum, $($t)*] [$crate::calc!(@operand $x)] $($r)*)
+    };
+    (@fold [] [$($t:tt)*] - $x:tt $($r:tt)*) => {
+        $crate::calc!(@fold [Diff, $($t)*] [$crate::calc!(@operand $x)] $($r)*)
+    };
+    (@fold [$f:ident, $($s:tt)*] [$($t:tt)*] + $x:tt $($r:tt)*) => {
+        $crate::calc!(@fold [Sum, $crate::operator_aliases::$f<$($s)*, $($t)*>] [$crate::calc!(@operand $x)] $($r)*)
+    };
+    (@fold [$f:ident, $($s:tt)*] [$($t:tt)*] - $x:tt $($r:tt)*) => {
+        $crate::calc!(@fold [Diff, $crate::operator_aliases::$f<$($s)*, $($t)*>] [$crate::calc!(@operand $x)] $($r)*)
+    };
+    (@fold [] [$($t:tt)*]) => {
+        $($t)*
+    };
+    (@fold [$f:ident, $($s:tt)*] [$($t:tt)*]) => {
+        $crate::operator_aliases::$f<$($s)*, $($t)*>
+    };
+
+    (@operand [$l:literal]) => {
+        $crate::Const<$l>
+    };
+    (@operand [- $l:literal]) => {
+        $crate::Const<-$l>
+    };
+    (@operand [($($e:tt)+)]) => {
+        $crate::calc!($($e)+)
+    };
+    (@operand [$($t:tt)+]) => {
+        $($t)+
+    };
+
+    ($($e:tt)+) => {
+        $crate::calc!(@tok [] [] [] $($e)+)
+    };
+}
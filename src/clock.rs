@@ -0,0 +1,90 @@
+//! Timer prescaler & auto-reload computation.
+//!
+//! A timer driven by `Clock` overflows with the frequency `Clock / ((PSC + 1) * (ARR + 1))`, where `PSC` & `ARR` are register values not exceeding `Max`.
+//! [`TimerConfig`] picks the smallest prescaler which fits the counter, so the resolution of the auto-reload value is as high as possible.
+//! Unreachable targets (faster than the clock or too slow for `Max`) fail to compile.
+
+use crate::{
+    expr,
+    ops::{CeilQuot, DivCeil},
+    Const, ConstResult, ToConstResult, ToTypenum, Typenum,
+};
+use core::ops::{Add, Div, Sub};
+use typenum::{consts::P1, Diff, Integer, IsGreaterOrEqual, IsLessOrEqual, Quot, Sum, B1};
+
+/// Computes register values making a timer driven by `Self` overflow with the frequency `Target`
+///
+/// ```rust
+/// use typenum_alias::{clock::{AutoReload, Prescaler}, Const};
+/// use typenum::{Integer, Prod, P1000, P16, P255, P50};
+///
+/// // 16 kHz clock, 50 Hz overflow, 8-bit registers: 16000 / ((1 + 1) * (159 + 1)) = 50
+/// type Clock = Prod<P1000, P16>;
+/// assert_eq!(<Prescaler<Clock, P50, P255> as Integer>::I32, 1);
+/// assert_eq!(<AutoReload<Clock, P50, P255> as Integer>::I32, 159);
+///
+/// let _: Prescaler<Const<16>, Const<2>, Const<3>> = Const::<1>;
+/// let _: AutoReload<Const<16>, Const<2>, Const<3>> = Const::<3>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{clock::Prescaler, Const};
+///
+/// // The target is faster than the clock
+/// let _: Prescaler<Const<4>, Const<8>, Const<15>>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{clock::Prescaler, Const};
+///
+/// // The prescaler 7 doesn't fit into the registers of at most 1
+/// let _: Prescaler<Const<16>, Const<1>, Const<1>>;
+/// ```
+pub trait TimerConfig<Target, Max> {
+    type Prescaler;
+    type AutoReload;
+}
+
+/// Value of the prescaler register
+pub type Prescaler<Clock, Target, Max> = <Clock as TimerConfig<Target, Max>>::Prescaler;
+
+/// Value of the auto-reload register
+pub type AutoReload<Clock, Target, Max> = <Clock as TimerConfig<Target, Max>>::AutoReload;
+
+type Ticks<C, T> = Quot<C, T>;
+type Divider<C, T, M> = CeilQuot<Ticks<C, T>, Sum<M, P1>>;
+
+impl<C, T, M> TimerConfig<T, M> for C
+where
+    C: Integer + Div<T>,
+    T: Integer,
+    M: Integer + Add<P1>,
+    Ticks<C, T>: IsGreaterOrEqual<P1, Output = B1> + DivCeil<Sum<M, P1>> + Div<Divider<C, T, M>>,
+    Divider<C, T, M>: Sub<P1>,
+    Diff<Divider<C, T, M>, P1>: IsLessOrEqual<M, Output = B1>,
+    Quot<Ticks<C, T>, Divider<C, T, M>>: Sub<P1>,
+{
+    type Prescaler = Diff<Divider<C, T, M>, P1>;
+    type AutoReload = Diff<Quot<Ticks<C, T>, Divider<C, T, M>>, P1>;
+}
+
+impl<const C: i32, const T: i32, const M: i32> TimerConfig<Const<T>, Const<M>> for Const<C>
+where
+    Const<C>: ToTypenum,
+    Const<T>: ToTypenum,
+    Const<M>: ToTypenum,
+    Typenum<Const<C>>: TimerConfig<Typenum<Const<T>>, Typenum<Const<M>>>,
+    Prescaler<Typenum<Const<C>>, Typenum<Const<T>>, Typenum<Const<M>>>:
+        ToConstResult<expr::Prescaler<Const<C>, Const<T>, Const<M>>>,
+    AutoReload<Typenum<Const<C>>, Typenum<Const<T>>, Typenum<Const<M>>>:
+        ToConstResult<expr::AutoReload<Const<C>, Const<T>, Const<M>>>,
+{
+    type Prescaler = ConstResult<
+        Prescaler<Typenum<Const<C>>, Typenum<Const<T>>, Typenum<Const<M>>>,
+        expr::Prescaler<Const<C>, Const<T>, Const<M>>,
+    >;
+    type AutoReload = ConstResult<
+        AutoReload<Typenum<Const<C>>, Typenum<Const<T>>, Typenum<Const<M>>>,
+        expr::AutoReload<Const<C>, Const<T>, Const<M>>,
+    >;
+}
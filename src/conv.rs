@@ -0,0 +1,138 @@
+//! Output sizes of convolution & pooling layers as `Const`
+
+use crate::{Const, Constify, Expand, Normalize, Typenumify};
+use core::ops::{Add, Div, Mul, Sub};
+use typenum::{Cmp, Compare, Diff, Equal, Greater, Prod, Quot, Sum, P1, P2, Z0};
+
+/// Implemented by the result of comparing the padded input with the dilated kernel if the kernel fits
+#[diagnostic::on_unimplemented(
+    message = "the kernel `{K}` doesn't fit into the input `{In}`",
+    label = "the dilated kernel is larger than the padded input"
+)]
+pub trait KernelFits<In, K> {}
+
+impl<In, K> KernelFits<In, K> for Greater {}
+impl<In, K> KernelFits<In, K> for Equal {}
+
+/// The input size minus the extent of the dilated kernel: `In + 2 * Padding - Dilation * (K - 1) - 1`
+type Slack<In, K, P, D> = Diff<Diff<Sum<In, Prod<P2, P>>, Prod<D, Diff<K, P1>>>, P1>;
+
+/// The output size of a convolution of the input size `Self`, see [`ConvOut`]
+pub trait ConvOutput<K, Stride, Padding, Dilation> {
+    type Output;
+}
+
+/// The output size of a convolution of `In` with the kernel size `K`:
+/// `(In + 2 * Padding - Dilation * (K - 1) - 1) / Stride + 1`.
+///
+/// The arguments are `Const` or `typenum` integers & the output must be in the conversion table,
+/// the intermediate values are computed as `typenum`.
+///
+/// ```rust
+/// use typenum_alias::{conv::{ConvOut, ConvTransposeOut, PoolOut}, Const};
+/// use typenum::{P28, P32};
+///
+/// let _: ConvOut<Const<12>, Const<5>> = Const::<8>;
+/// let _: ConvOut<P32, Const<7>, Const<2>, Const<3>> = Const::<16>;
+/// let _: ConvOut<Const<8>, Const<3>, Const<1>, Const<2>, Const<2>> = Const::<8>;
+/// let _: PoolOut<P28, Const<2>> = Const::<14>;
+/// let _: ConvTransposeOut<Const<7>, Const<4>, Const<2>, Const<1>> = Const::<14>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{conv::ConvOut, Const};
+///
+/// // error: the kernel `Const<5>` doesn't fit into the input `Const<4>`
+/// let _: ConvOut<Const<4>, Const<5>>;
+/// ```
+pub type ConvOut<In, K, Stride = Const<1>, Padding = Const<0>, Dilation = Const<1>> =
+    <In as ConvOutput<K, Stride, Padding, Dilation>>::Output;
+
+/// The output size of pooling `In` with the window `K`, which moves by itself by default
+pub type PoolOut<In, K, Stride = K, Padding = Const<0>> = ConvOut<In, K, Stride, Padding>;
+
+impl<In, K, S, P, D> ConvOutput<K, S, P, D> for In
+where
+    In: Expand,
+    K: Expand,
+    S: Expand,
+    P: Expand,
+    D: Expand,
+    P2: Mul<Typenumify<P>>,
+    Typenumify<In>: Add<Prod<P2, Typenumify<P>>>,
+    Typenumify<K>: Sub<P1>,
+    Typenumify<D>: Mul<Diff<Typenumify<K>, P1>>,
+    Sum<Typenumify<In>, Prod<P2, Typenumify<P>>>: Sub<Prod<Typenumify<D>, Diff<Typenumify<K>, P1>>>,
+    Diff<
+        Sum<Typenumify<In>, Prod<P2, Typenumify<P>>>,
+        Prod<Typenumify<D>, Diff<Typenumify<K>, P1>>,
+    >: Sub<P1>,
+    Slack<Typenumify<In>, Typenumify<K>, Typenumify<P>, Typenumify<D>>:
+        Cmp<Z0> + Div<Typenumify<S>>,
+    Compare<Slack<Typenumify<In>, Typenumify<K>, Typenumify<P>, Typenumify<D>>, Z0>:
+        KernelFits<In, K>,
+    Quot<Slack<Typenumify<In>, Typenumify<K>, Typenumify<P>, Typenumify<D>>, Typenumify<S>>:
+        Add<P1>,
+    Sum<
+        Quot<Slack<Typenumify<In>, Typenumify<K>, Typenumify<P>, Typenumify<D>>, Typenumify<S>>,
+        P1,
+    >: Normalize,
+{
+    type Output = Constify<
+        Sum<
+            Quot<Slack<Typenumify<In>, Typenumify<K>, Typenumify<P>, Typenumify<D>>, Typenumify<S>>,
+            P1,
+        >,
+    >;
+}
+
+/// The output size of a transposed convolution of the input size `Self`, see [`ConvTransposeOut`]
+pub trait ConvTransposeOutput<K, Stride, Padding, Dilation> {
+    type Output;
+}
+
+/// The output size of a transposed convolution of `In` with the kernel size `K`, the inverse of [`ConvOut`]:
+/// `(In - 1) * Stride - 2 * Padding + Dilation * (K - 1) + 1`
+pub type ConvTransposeOut<In, K, Stride = Const<1>, Padding = Const<0>, Dilation = Const<1>> =
+    <In as ConvTransposeOutput<K, Stride, Padding, Dilation>>::Output;
+
+/// `(In - 1) * Stride - 2 * Padding`
+type Stretched<In, S, P> = Diff<Prod<Diff<In, P1>, S>, Prod<P2, P>>;
+
+impl<In, K, S, P, D> ConvTransposeOutput<K, S, P, D> for In
+where
+    In: Expand,
+    K: Expand,
+    S: Expand,
+    P: Expand,
+    D: Expand,
+    P2: Mul<Typenumify<P>>,
+    Typenumify<In>: Sub<P1>,
+    Diff<Typenumify<In>, P1>: Mul<Typenumify<S>>,
+    Prod<Diff<Typenumify<In>, P1>, Typenumify<S>>: Sub<Prod<P2, Typenumify<P>>>,
+    Typenumify<K>: Sub<P1>,
+    Typenumify<D>: Mul<Diff<Typenumify<K>, P1>>,
+    Stretched<Typenumify<In>, Typenumify<S>, Typenumify<P>>:
+        Add<Prod<Typenumify<D>, Diff<Typenumify<K>, P1>>>,
+    Sum<
+        Stretched<Typenumify<In>, Typenumify<S>, Typenumify<P>>,
+        Prod<Typenumify<D>, Diff<Typenumify<K>, P1>>,
+    >: Add<P1>,
+    Sum<
+        Sum<
+            Stretched<Typenumify<In>, Typenumify<S>, Typenumify<P>>,
+            Prod<Typenumify<D>, Diff<Typenumify<K>, P1>>,
+        >,
+        P1,
+    >: Normalize,
+{
+    type Output = Constify<
+        Sum<
+            Sum<
+                Stretched<Typenumify<In>, Typenumify<S>, Typenumify<P>>,
+                Prod<Typenumify<D>, Diff<Typenumify<K>, P1>>,
+            >,
+            P1,
+        >,
+    >;
+}
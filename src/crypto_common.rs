@@ -0,0 +1,95 @@
+//! Sizes of `crypto-common` as `Const`, and macros declaring them without `typenum`
+
+use crate::Constify;
+use crypto_common::{BlockSizeUser, IvSizeUser, KeySizeUser};
+
+/// The block size of `C` as `Const`
+pub type BlockSize<C> = Constify<<C as BlockSizeUser>::BlockSize>;
+
+/// The key size of `C` as `Const`
+pub type KeySize<C> = Constify<<C as KeySizeUser>::KeySize>;
+
+/// The IV size of `C` as `Const`
+pub type IvSize<C> = Constify<<C as IvSizeUser>::IvSize>;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __size {
+    ($name:ident, $n:literal) => {
+        type $name = $crate::hybrid_array::Size<$crate::Const<$n>>;
+    };
+    ($name:ident, $n:ty) => {
+        type $name = $crate::hybrid_array::Size<$n>;
+    };
+}
+
+/// Declares `BlockSize` in an impl of `BlockSizeUser` by a number or a `Const` expression.
+///
+/// ```rust
+/// use crypto_common::{BlockSizeUser, IvSizeUser, KeySizeUser, OutputSizeUser};
+/// use typenum_alias::{block_size, crypto_common::BlockSize, iv_size, key_size, output_size, Const};
+/// use typenum::Prod;
+///
+/// struct Cipher;
+///
+/// impl BlockSizeUser for Cipher {
+///     block_size!(64);
+/// }
+///
+/// impl KeySizeUser for Cipher {
+///     key_size!(Prod<Const<8>, Const<4>>);
+/// }
+///
+/// impl IvSizeUser for Cipher {
+///     iv_size!(16);
+/// }
+///
+/// impl OutputSizeUser for Cipher {
+///     output_size!(32);
+/// }
+///
+/// let _: BlockSize<Cipher> = Const::<64>;
+/// assert_eq!(Cipher::key_size(), 32);
+/// ```
+#[macro_export]
+macro_rules! block_size {
+    ($n:tt) => {
+        $crate::__size!(BlockSize, $n);
+    };
+    ($n:ty) => {
+        $crate::__size!(BlockSize, $n);
+    };
+}
+
+/// Declares `KeySize` in an impl of `KeySizeUser`, see [`block_size!`](crate::block_size)
+#[macro_export]
+macro_rules! key_size {
+    ($n:tt) => {
+        $crate::__size!(KeySize, $n);
+    };
+    ($n:ty) => {
+        $crate::__size!(KeySize, $n);
+    };
+}
+
+/// Declares `IvSize` in an impl of `IvSizeUser`, see [`block_size!`](crate::block_size)
+#[macro_export]
+macro_rules! iv_size {
+    ($n:tt) => {
+        $crate::__size!(IvSize, $n);
+    };
+    ($n:ty) => {
+        $crate::__size!(IvSize, $n);
+    };
+}
+
+/// Declares `OutputSize` in an impl of `OutputSizeUser`, see [`block_size!`](crate::block_size)
+#[macro_export]
+macro_rules! output_size {
+    ($n:tt) => {
+        $crate::__size!(OutputSize, $n);
+    };
+    ($n:ty) => {
+        $crate::__size!(OutputSize, $n);
+    };
+}
@@ -0,0 +1,183 @@
+//! Recursive conversions of types, which contain `Const` or `typenum` integers, in both directions
+
+use crate::{ops::ToInteger, Const, Constant, ToConst, ToTypenum, Typenum};
+use core::marker::PhantomData;
+use typenum::{
+    ATerm, Bit, Equal, Greater, Less, NInt, NonZero, PInt, TArr, UInt, UTerm, Unsigned, B0, B1, Z0,
+};
+
+/// Converts every `typenum` integer inside of `Self` to `Const`, see [`Constify`].
+///
+/// Implemented for `Const`, `typenum` integers, bits & orderings, `typenum` arrays, tuples, arrays & `PhantomData`.
+/// Generic types of other crates may implement it for their parameters:
+///
+/// ```rust
+/// use typenum_alias::{Const, Constify, Normalize};
+/// use typenum::{Prod, Sum, P2, U3};
+///
+/// struct Buffer<N>(N);
+///
+/// impl<N: Normalize> Normalize for Buffer<N> {
+///     type Output = Buffer<Constify<N>>;
+/// }
+///
+/// let _: Constify<(Buffer<Sum<P2, Prod<P2, P2>>>, [U3; 2])> = (Buffer(Const::<6>), [Const::<3>; 2]);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be normalized to `Const`",
+    label = "neither a `Const` or `typenum` integer, nor a type containing them",
+    note = "implement `Normalize` for generic types, which contain them, and integers must be in the conversion table"
+)]
+pub trait Normalize {
+    type Output;
+}
+
+/// `T` with every `typenum` integer replaced by `Const`, e. g. `(U3, Sum<P2, P4>)` becomes `(Const<3>, Const<6>)`
+pub type Constify<T> = <T as Normalize>::Output;
+
+/// Converts every `Const` inside of `Self` to `typenum`, see [`Typenumify`]. The inverse of [`Normalize`].
+///
+/// Implemented for the same types as [`Normalize`], generic types of other crates may implement it too:
+///
+/// ```rust
+/// use typenum_alias::{Const, Expand, Typenumify};
+/// use typenum::{P3, Z0};
+///
+/// struct Buffer<N>(N);
+///
+/// impl<N: Expand> Expand for Buffer<N> {
+///     type Output = Buffer<Typenumify<N>>;
+/// }
+///
+/// let _: Typenumify<(Buffer<Const<0>>, [Const<3>; 2])> = (Buffer(Z0), [P3::new(); 2]);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be expanded to `typenum`",
+    label = "neither a `Const` or `typenum` integer, nor a type containing them",
+    note = "implement `Expand` for generic types, which contain them, and `Const`s must be in the conversion table"
+)]
+pub trait Expand {
+    type Output;
+}
+
+/// `T` with every `Const` replaced by `typenum`, e. g. `(Const<3>, U2)` becomes `(P3, U2)`
+pub type Typenumify<T> = <T as Expand>::Output;
+
+impl<const N: i32> Normalize for Const<N> {
+    type Output = Const<N>;
+}
+
+impl<const N: i32> Expand for Const<N>
+where
+    Const<N>: ToTypenum,
+{
+    type Output = Typenum<Const<N>>;
+}
+
+impl Expand for Z0 {
+    type Output = Self;
+}
+
+impl<U: Unsigned + NonZero> Expand for PInt<U> {
+    type Output = Self;
+}
+
+impl<U: Unsigned + NonZero> Expand for NInt<U> {
+    type Output = Self;
+}
+
+impl Expand for UTerm {
+    type Output = Self;
+}
+
+impl<U: Unsigned, B: Bit> Expand for UInt<U, B> {
+    type Output = Self;
+}
+
+impl Normalize for Z0 {
+    type Output = Const<0>;
+}
+
+impl<U: Unsigned + NonZero> Normalize for PInt<U>
+where
+    PInt<U>: ToConst,
+{
+    type Output = Constant<PInt<U>>;
+}
+
+impl<U: Unsigned + NonZero> Normalize for NInt<U>
+where
+    NInt<U>: ToConst,
+{
+    type Output = Constant<NInt<U>>;
+}
+
+impl Normalize for UTerm {
+    type Output = Const<0>;
+}
+
+impl<U: Unsigned, B: Bit> Normalize for UInt<U, B>
+where
+    UInt<U, B>: ToInteger,
+    <UInt<U, B> as ToInteger>::Output: ToConst,
+{
+    type Output = Constant<<UInt<U, B> as ToInteger>::Output>;
+}
+
+macro_rules! impl_identity {
+    ($($t:ty),+) => {
+        $(impl Normalize for $t {
+            type Output = $t;
+        }
+
+        impl Expand for $t {
+            type Output = $t;
+        })+
+    };
+}
+
+impl_identity!(B0, B1, Less, Equal, Greater, ATerm);
+
+macro_rules! impl_containers {
+    ($($tr:ident => $alias:ident),+) => {
+        $(impl<V: $tr, A: $tr> $tr for TArr<V, A> {
+            type Output = TArr<$alias<V>, $alias<A>>;
+        }
+
+        impl<T: $tr, const N: usize> $tr for [T; N] {
+            type Output = [$alias<T>; N];
+        }
+
+        impl<T: $tr> $tr for PhantomData<T> {
+            type Output = PhantomData<$alias<T>>;
+        })+
+    };
+}
+
+impl_containers!(Normalize => Constify, Expand => Typenumify);
+
+macro_rules! impl_tuples {
+    ($($t:ident)*) => {
+        impl<$($t: Normalize),*> Normalize for ($($t,)*) {
+            type Output = ($(Constify<$t>,)*);
+        }
+
+        impl<$($t: Expand),*> Expand for ($($t,)*) {
+            type Output = ($(Typenumify<$t>,)*);
+        }
+    };
+}
+
+impl_tuples!();
+impl_tuples!(A);
+impl_tuples!(A B);
+impl_tuples!(A B C);
+impl_tuples!(A B C D);
+impl_tuples!(A B C D E);
+impl_tuples!(A B C D E F);
+impl_tuples!(A B C D E F G);
+impl_tuples!(A B C D E F G H);
+impl_tuples!(A B C D E F G H I);
+impl_tuples!(A B C D E F G H I J);
+impl_tuples!(A B C D E F G H I J K);
+impl_tuples!(A B C D E F G H I J K L);
@@ -0,0 +1,35 @@
+//! Output sizes of `digest` as `Const`
+
+use crate::{kdf::ExpandBlocks, Constify, Normalize};
+use digest::OutputSizeUser;
+
+/// The output size of `D` as `Const`
+pub type OutputSize<D> = Constify<<D as OutputSizeUser>::OutputSize>;
+
+/// The number of HMAC blocks HKDF with `D` computes to expand `Len` bytes, see [`OkmLen`](crate::kdf::OkmLen)
+pub type HkdfBlocks<D, Len> = ExpandBlocks<Len, OutputSize<D>>;
+
+/// Implemented by hashes with the output size `N`, which is a `Const`: `fn f<D: Digest + OutputSizeIs<Const<32>>>()`.
+///
+/// The output size is declared with [`Size`](crate::hybrid_array::Size):
+///
+/// ```rust
+/// use digest::OutputSizeUser;
+/// use typenum_alias::{digest::{OutputSize, OutputSizeIs}, hybrid_array::Size, Const};
+///
+/// struct Hash;
+///
+/// impl OutputSizeUser for Hash {
+///     type OutputSize = Size<Const<32>>;
+/// }
+///
+/// fn output_len<D: OutputSizeIs<Const<32>>>() -> usize {
+///     D::output_size()
+/// }
+///
+/// let _: OutputSize<Hash> = Const::<32>;
+/// assert_eq!(output_len::<Hash>(), 32);
+/// ```
+pub trait OutputSizeIs<N>: OutputSizeUser {}
+
+impl<D: OutputSizeUser, N> OutputSizeIs<N> for D where D::OutputSize: Normalize<Output = N> {}
@@ -0,0 +1,72 @@
+//! Unit exponents of `dimensioned` as `Const`: `Si<f64, 1, 0, -2, 0, 0, 0, 0>` instead of
+//! `SI<f64, tarr![P1, Z0, N2, Z0, Z0, Z0, Z0]>`
+
+use crate::{
+    assert::ConstEq,
+    list::{FromTArr, TArrOf, TupleOf},
+    Const, Constify, Expand, Normalize, Typenumify,
+};
+use dimensioned::{cgs::CGS, fps::FPS, mks::MKS, si::SI, ucum::UCUM, Dimensioned};
+
+/// The unit exponents of a quantity as a tuple of `Const`: `Exponents<si::Meter<f64>>` is
+/// `(Const<1>, Const<0>, Const<0>, Const<0>, Const<0>, Const<0>, Const<0>)`
+pub type Exponents<Q> = TupleOf<<Q as Dimensioned>::Units>;
+
+/// Implemented by quantities with the unit exponents `E`, which is a tuple of `Const`.
+/// Mismatches fail with both exponents as `Const`, rather than as `typenum` arrays.
+///
+/// ```rust
+/// use dimensioned::si;
+/// use typenum_alias::{dimensioned::{Si, UnitsAre}, Const};
+///
+/// type C0 = Const<0>;
+/// type Velocity<V> = Si<V, 1, 0, -1, 0, 0, 0, 0>;
+///
+/// fn speed<Q: UnitsAre<(Const<1>, C0, Const<-1>, C0, C0, C0, C0)>>(_: Q) {}
+///
+/// let v: Velocity<f64> = 3.0 * si::M / si::S;
+/// speed(v);
+/// ```
+///
+/// ```rust,compile_fail
+/// use dimensioned::si;
+/// use typenum_alias::{dimensioned::UnitsAre, Const};
+///
+/// type C0 = Const<0>;
+///
+/// fn speed<Q: UnitsAre<(Const<1>, C0, Const<-1>, C0, C0, C0, C0)>>(_: Q) {}
+///
+/// // error: `(Const<1>, Const<0>, Const<-2>, ...)` isn't equal to `(Const<1>, Const<0>, Const<-1>, ...)`
+/// speed(3.0 * si::M / si::S / si::S);
+/// ```
+pub trait UnitsAre<E>: Dimensioned {}
+
+impl<Q: Dimensioned, E> UnitsAre<E> for Q
+where
+    Q::Units: FromTArr,
+    Exponents<Q>: ConstEq<E>,
+{
+}
+
+macro_rules! impl_system {
+    ($($alias:ident => $system:ident($($e:ident),+);)+) => {
+        $(#[doc = concat!("`", stringify!($system), "` with the unit exponents as `Const`")]
+        pub type $alias<V, $(const $e: i32),+> = $system<V, TArrOf<($(Const<$e>,)+)>>;
+
+        impl<V, U: Normalize> Normalize for $system<V, U> {
+            type Output = $system<V, Constify<U>>;
+        }
+
+        impl<V, U: Expand> Expand for $system<V, U> {
+            type Output = $system<V, Typenumify<U>>;
+        })+
+    };
+}
+
+impl_system! {
+    Si => SI(M, KG, S, A, K, CD, MOL);
+    Ucum => UCUM(M, S, G, RAD, K, C, CD);
+    Mks => MKS(SQRTM, SQRTKG, S);
+    Cgs => CGS(SQRTCM, SQRTG, S);
+    Fps => FPS(SQRTFT, SQRTLB, S);
+}
@@ -0,0 +1,141 @@
+//! Bridges runtime integers to type-level code by matching them against the conversion table.
+
+use crate::{Const, ConstError, ToTypenum};
+use core::fmt;
+
+#[cfg(feature = "rkyv")]
+pub use crate::impls::rkyv::ArchivedDynConst;
+
+/// A function generic over a constant, since closures can't be generic.
+/// State is captured in the fields of the implementing type.
+pub trait ConstFn {
+    type Output;
+
+    fn call<N: ToTypenum + Default + Copy>(self, n: N) -> Self::Output;
+}
+
+/// Calls `f` with `Const<value>`, or returns `None` if `value` is outside of the conversion table.
+///
+/// Every `Const` in the table is monomorphized. The `with_const!` macro from the `macros` feature dispatches
+/// over a smaller range.
+///
+/// ```rust
+/// use typenum_alias::{dispatch::{with_const, ConstFn}, ToTypenum, Typenum};
+/// use typenum::Integer;
+///
+/// struct Scale(i32);
+///
+/// impl ConstFn for Scale {
+///     type Output = i32;
+///
+///     fn call<N: ToTypenum + Default + Copy>(self, _: N) -> i32 {
+///         self.0 * Typenum::<N>::I32
+///     }
+/// }
+///
+/// assert_eq!(with_const(-3, Scale(2)), Some(-6));
+/// assert_eq!(with_const(100_000, Scale(2)), None);
+/// ```
+pub fn with_const<F: ConstFn>(value: i32, f: F) -> Option<F::Output> {
+    macro_rules! dispatch {
+        ($($num:literal),+) => {
+            match value {
+                0 => Some(f.call(Const::<0>)),
+                $($num => Some(f.call(Const::<$num>)),
+                -$num => Some(f.call(Const::<-$num>)),)+
+                _ => None,
+            }
+        };
+    }
+
+    with_table!(dispatch)
+}
+
+/// A constant from the conversion table, known only at runtime.
+///
+/// Converts from any `Const<N>` in the table & back with `TryFrom`, so values can pass through non-generic code.
+/// [`DynConst::with`] calls generic code with the original `Const<N>`.
+///
+/// ```rust
+/// use typenum_alias::{dispatch::DynConst, Const};
+///
+/// let erased = DynConst::from(Const::<12>);
+/// assert_eq!(erased.get(), 12);
+/// assert!(Const::<12>::try_from(erased).is_ok());
+/// assert!(Const::<13>::try_from(erased).is_err());
+/// assert!(DynConst::try_from(1 << 20).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynConst(i32);
+
+impl DynConst {
+    /// The bound of the conversion table, `DynConst` holds values in `-MAX..=MAX`
+    pub const MAX: i32 = crate::TABLE_MAX;
+
+    /// Returns `None` if `value` is outside of the conversion table
+    #[must_use]
+    pub const fn new(value: i32) -> Option<Self> {
+        if value.unsigned_abs() <= Self::MAX.unsigned_abs() {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    /// Calls `f` with the `Const` this value was erased from
+    pub fn with<F: ConstFn>(self, f: F) -> F::Output {
+        with_const(self.0, f)
+            .unwrap_or_else(|| unreachable!("`DynConst` is always in the conversion table"))
+    }
+}
+
+impl<const N: i32> From<Const<N>> for DynConst
+where
+    Const<N>: ToTypenum,
+{
+    #[inline]
+    fn from(_: Const<N>) -> Self {
+        Self(N)
+    }
+}
+
+impl TryFrom<i32> for DynConst {
+    type Error = ConstError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        Self::new(value).ok_or(ConstError::OutOfTable { value: value.into(), max: Self::MAX })
+    }
+}
+
+impl<const N: i32> TryFrom<DynConst> for Const<N>
+where
+    Const<N>: ToTypenum,
+{
+    type Error = ConstError;
+
+    fn try_from(value: DynConst) -> Result<Self, Self::Error> {
+        if value.0 == N {
+            Ok(Const)
+        } else {
+            Err(ConstError::Mismatch { expected: N, found: value.0.into() })
+        }
+    }
+}
+
+impl From<DynConst> for i32 {
+    #[inline]
+    fn from(value: DynConst) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for DynConst {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
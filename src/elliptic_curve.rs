@@ -0,0 +1,62 @@
+//! Field, scalar & point sizes of `elliptic-curve` as `Const`
+
+use crate::{Const, Constify};
+use elliptic_curve::{Curve, FieldBytes};
+use hybrid_array::{Array, ArraySize};
+use typenum::{Prod, Sum};
+
+/// The size of a serialized field element of `C` as `Const`
+pub type FieldSize<C> = Constify<<C as Curve>::FieldBytesSize>;
+
+/// The size of a serialized scalar of `C` as `Const`, which is the same as [`FieldSize`]
+pub type ScalarSize<C> = FieldSize<C>;
+
+/// The size of a SEC1 compressed point of `C` as `Const`: a tag byte & the x-coordinate
+pub type CompressedSize<C> = Sum<FieldSize<C>, Const<1>>;
+
+/// The size of a SEC1 uncompressed point of `C` as `Const`: a tag byte & both coordinates
+pub type UncompressedSize<C> = Sum<Prod<FieldSize<C>, Const<2>>, Const<1>>;
+
+/// The field bytes of `C` from an array of their length, other lengths don't compile.
+///
+/// ```rust
+/// use elliptic_curve::{bigint::{Odd, U256}, Curve};
+/// use typenum_alias::{
+///     elliptic_curve::{field_bytes, to_array, CompressedSize, FieldSize, UncompressedSize},
+///     hybrid_array::Size,
+///     Const,
+/// };
+///
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+/// struct P256;
+///
+/// impl Curve for P256 {
+///     type FieldBytesSize = Size<Const<32>>;
+///     type Uint = U256;
+///     const ORDER: Odd<U256> =
+///         Odd::<U256>::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+/// }
+///
+/// let _: (FieldSize<P256>, CompressedSize<P256>, UncompressedSize<P256>) =
+///     (Const::<32>, Const::<33>, Const::<65>);
+/// let bytes = field_bytes::<P256, 32>([7; 32]);
+/// assert_eq!(to_array::<P256, 32>(bytes), [7; 32]);
+/// ```
+#[must_use]
+pub fn field_bytes<C, const N: usize>(bytes: [u8; N]) -> FieldBytes<C>
+where
+    C: Curve,
+    C::FieldBytesSize: ArraySize<ArrayType<u8> = [u8; N]>,
+{
+    Array(bytes)
+}
+
+/// The field bytes of `C` as an array of their length
+#[must_use]
+pub fn to_array<C, const N: usize>(bytes: FieldBytes<C>) -> [u8; N]
+where
+    C: Curve,
+    C::FieldBytesSize: ArraySize<ArrayType<u8> = [u8; N]>,
+{
+    bytes.0
+}
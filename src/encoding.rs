@@ -0,0 +1,43 @@
+//! Lengths of hex & base64 encodings & worst-case lengths of COBS & SLIP frames as `Const`
+//!
+//! The arguments are `Const` or `typenum` integers, which are computed as `typenum`. The results must be
+//! in the conversion table.
+
+use crate::{ops::CeilQuot, Constify, Typenumify};
+use typenum::{Prod, Sum, P1, P2, P254, P3, P4};
+
+/// The length of `N` bytes encoded as hex, `2 * N`
+///
+/// ```rust
+/// use typenum_alias::{encoding::{Base64Len, Base64UnpaddedLen, HexLen}, Const};
+///
+/// let _: HexLen<Const<8>> = Const::<16>;
+/// let _: Base64Len<Const<8>> = Const::<12>;
+/// let _: Base64UnpaddedLen<Const<8>> = Const::<11>;
+/// let _: Base64UnpaddedLen<Const<9>> = Const::<12>;
+/// let buffer = [0_u8; HexLen::<Const<4>>::USIZE];
+/// assert_eq!(buffer.len(), 8);
+/// ```
+pub type HexLen<N> = Constify<Prod<Typenumify<N>, P2>>;
+
+/// The length of `N` bytes encoded as base64 with padding, `4 * ceil(N / 3)`
+pub type Base64Len<N> = Constify<Prod<CeilQuot<Typenumify<N>, P3>, P4>>;
+
+/// The length of `N` bytes encoded as base64 without padding, `ceil(4 * N / 3)`
+pub type Base64UnpaddedLen<N> = Constify<CeilQuot<Prod<Typenumify<N>, P4>, P3>>;
+
+/// The worst-case length of `N` bytes encoded by COBS with the `0` delimiter, `N + ceil(N / 254) + 1`
+///
+/// ```rust
+/// use typenum_alias::{encoding::{CobsMaxLen, SlipMaxLen}, Const};
+///
+/// let _: CobsMaxLen<Const<12>> = Const::<14>;
+/// let _: SlipMaxLen<Const<7>> = Const::<16>;
+/// let frame = [0_u8; CobsMaxLen::<Const<8>>::USIZE];
+/// assert_eq!(frame.len(), 10);
+/// ```
+pub type CobsMaxLen<N> = Constify<Sum<Sum<Typenumify<N>, CeilQuot<Typenumify<N>, P254>>, P1>>;
+
+/// The worst-case length of `N` bytes encoded by SLIP, where each byte is escaped, with `END` before
+/// & after the frame: `2 * N + 2`
+pub type SlipMaxLen<N> = Constify<Sum<Prod<Typenumify<N>, P2>, P2>>;
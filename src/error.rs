@@ -0,0 +1,34 @@
+use core::fmt;
+
+/// The error of runtime conversions to constants
+///
+/// ```rust
+/// use typenum_alias::{Const, ConstError};
+///
+/// assert_eq!(Const::<5>::try_from(5_i32), Ok(Const::<5>));
+/// assert_eq!(Const::<5>::try_from(6_i32), Err(ConstError::Mismatch { expected: 5, found: 6 }));
+/// assert_eq!(Const::<5>::try_from(5_usize), Ok(Const::<5>));
+/// assert!(Const::<-1>::try_from(usize::MAX).is_err());
+/// assert_eq!(Const::<5>::try_from(6_i32).unwrap_err().to_string(), "expected `Const<5>`, found 6");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstError {
+    /// The value is outside of the conversion table, `-max..=max`
+    OutOfTable { value: i128, max: i32 },
+    /// The value differs from the expected constant
+    Mismatch { expected: i32, found: i128 },
+}
+
+impl fmt::Display for ConstError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfTable { value, max } => write!(
+                f,
+                "{value} is outside of the conversion table `-{max}..={max}`, enable a bigger `consts-*` feature"
+            ),
+            Self::Mismatch { expected, found } => write!(f, "expected `Const<{expected}>`, found {found}"),
+        }
+    }
+}
+
+impl core::error::Error for ConstError {}
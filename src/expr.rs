@@ -0,0 +1,89 @@
+//! Operations on `Const`, which name results outside of the conversion table in errors, e. g.
+//! `` the result of `Prod<Const<200>, Const<2>>` is outside of the conversion table ``.
+//! They aren't meant to be used directly, use the operator aliases instead.
+
+use crate::{ToTypenum, Typenum};
+use core::marker::PhantomData;
+use typenum::{NInt, NonZero, PInt, PartialDiv, Unsigned, Z0};
+
+macro_rules! expressions {
+    ($($name:ident),+ $(,)?) => {
+        $(pub struct $name<L, R = ()>(PhantomData<(L, R)>);)+
+    };
+}
+
+expressions! {
+    Sum, Diff, Prod, Quot, Mod, Maximum, Minimum, PartialQuot, Gcf, CeilQuot, WithSign,
+    Negate, AbsVal, Log2, Sqrt, Sign,
+}
+
+/// The registers of [`TimerConfig`](crate::clock::TimerConfig) for the frequencies `Clock` & `Target` & the register maximum `Max`
+pub struct Prescaler<Clock, Target, Max>(PhantomData<(Clock, Target, Max)>);
+
+/// See [`Prescaler`]
+pub struct AutoReload<Clock, Target, Max>(PhantomData<(Clock, Target, Max)>);
+
+// Operations use the outputs of these checks instead of their operands, so that a failed check is the only error
+
+/// Implemented by nonzero `typenum` divisors of `L`
+///
+/// ```rust
+/// use typenum_alias::Const;
+/// use typenum::{Mod, Quot};
+///
+/// let _: Const<-3> = Const::<-7> / Const::<2>;
+/// let _: Const<-1> = Const::<-7> % Const::<2>;
+/// let _: Quot<Const<12>, Const<-4>> = Const::<-3>;
+/// let _: Mod<Const<12>, Const<5>> = Const::<2>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::Const;
+///
+/// // error: attempt to divide `Const<7>` by zero
+/// let _ = Const::<7> / Const::<0>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::Const;
+///
+/// // error: attempt to divide `Const<7>` by zero
+/// let _ = Const::<7> % Const::<0>;
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "attempt to divide `{L}` by zero",
+    label = "division by zero"
+)]
+pub trait NonZeroDivisor<L> {
+    type Output;
+}
+
+impl<L, U: Unsigned + NonZero> NonZeroDivisor<L> for PInt<U> {
+    type Output = Self;
+}
+
+impl<L, U: Unsigned + NonZero> NonZeroDivisor<L> for NInt<U> {
+    type Output = Self;
+}
+
+/// The `typenum` divisor `R` of `L`, if it's nonzero
+pub type Divisor<L, R> = <Typenum<R> as NonZeroDivisor<L>>::Output;
+
+/// Implemented by the remainder of `L / R` if it's zero, outputs the quotient
+#[diagnostic::on_unimplemented(
+    message = "`{L}` isn't divisible by `{R}`",
+    label = "`PartialDiv` requires exact division"
+)]
+pub trait Divisible<L, R> {
+    type Output;
+}
+
+impl<L: ToTypenum, R: ToTypenum> Divisible<L, R> for Z0
+where
+    Typenum<L>: PartialDiv<Typenum<R>>,
+{
+    type Output = typenum::PartialQuot<Typenum<L>, Typenum<R>>;
+}
+
+/// The `typenum` quotient `L / R`, if `L` is divisible by `R`
+pub type ExactQuot<L, R> = <typenum::Mod<Typenum<L>, Divisor<L, R>> as Divisible<L, R>>::Output;
@@ -0,0 +1,45 @@
+//! Radix-2 FFT size validation.
+//!
+//! `Const<N>: FftSize` holds only when `N` is a power of two, so FFT kernels generic over the size get their bounds checked by the compiler.
+
+use crate::{Const, ToTypenum, Typenum};
+use core::ops::Div;
+use typenum::{Log2, Logarithm2, PowerOfTwo, Quot};
+
+/// ```rust
+/// use typenum_alias::{fft::{FftSize, Stages, Twiddles}, Const};
+///
+/// let _: Stages<Const<16>> = Const::<4>;
+/// let _: Twiddles<Const<16>> = Const::<8>;
+/// let _: Stages<Const<1>> = Const::<0>;
+///
+/// fn butterflies<N: FftSize>() {}
+/// butterflies::<Const<8>>();
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{fft::FftSize, Const};
+///
+/// fn butterflies<N: FftSize>() {}
+/// // error: `Const<12>: FftSize` isn't satisfied, 12 isn't a power of two
+/// butterflies::<Const<12>>();
+/// ```
+pub trait FftSize {
+    /// Number of butterfly stages, `log2(N)`
+    type Stages;
+    /// Length of the twiddle factor table, `N / 2`
+    type Twiddles;
+}
+
+pub type Stages<N> = <N as FftSize>::Stages;
+
+pub type Twiddles<N> = <N as FftSize>::Twiddles;
+
+impl<const N: i32> FftSize for Const<N>
+where
+    Const<N>: ToTypenum + Logarithm2 + Div<Const<2>>,
+    Typenum<Const<N>>: PowerOfTwo,
+{
+    type Stages = Log2<Const<N>>;
+    type Twiddles = Quot<Const<N>, Const<2>>;
+}
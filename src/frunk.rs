@@ -0,0 +1,125 @@
+//! Lengths of `frunk` `HList`s as `Const` & access to their elements by `Const` indices
+
+use crate::{assert::ConstEq, Const};
+use core::ops::Add;
+use frunk_core::hlist::{HCons, HList, HNil};
+use typenum::Sum;
+
+/// The length of an `HList` as `Const`
+pub trait ToLen {
+    type Output;
+}
+
+/// `Len<HList![u8, bool]>` is `Const<2>`
+pub type Len<L> = <L as ToLen>::Output;
+
+impl ToLen for HNil {
+    type Output = Const<0>;
+}
+
+impl<H, T: ToLen> ToLen for HCons<H, T>
+where
+    Len<T>: Add<Const<1>>,
+{
+    type Output = Sum<Len<T>, Const<1>>;
+}
+
+/// Implemented by `HList`s of the length `N`, which is a `Const`: `fn f<L: LenIs<Const<3>>>(list: L)`.
+/// Other lengths fail with `` `Const<4>` isn't equal to `Const<3>` ``.
+///
+/// ```rust
+/// use frunk_core::hlist;
+/// use typenum_alias::{frunk::LenIs, Const};
+///
+/// fn rgb<L: LenIs<Const<3>>>(_: L) {}
+///
+/// rgb(hlist![1_u8, 2_u16, 3_u32]);
+/// ```
+///
+/// ```rust,compile_fail
+/// use frunk_core::hlist;
+/// use typenum_alias::{frunk::LenIs, Const};
+///
+/// fn rgb<L: LenIs<Const<3>>>(_: L) {}
+///
+/// rgb(hlist![1_u8, 2_u16, 3_u32, 4_u64]);
+/// ```
+pub trait LenIs<N>: HList {}
+
+impl<L: HList + ToLen, N> LenIs<N> for L where Len<L>: ConstEq<N> {}
+
+/// Access to the element of an `HList` at the index `I`, which is a `Const`, see [`Elem`]
+#[diagnostic::on_unimplemented(
+    message = "the `HList` `{Self}` has no element at `{I}`",
+    label = "index out of bounds"
+)]
+pub trait At<I> {
+    type Output;
+
+    fn at(&self, index: I) -> &Self::Output;
+
+    fn at_mut(&mut self, index: I) -> &mut Self::Output;
+
+    fn into_at(self, index: I) -> Self::Output;
+}
+
+/// The type of the element of `L` at `I`
+///
+/// ```rust
+/// use frunk_core::{hlist, HList};
+/// use typenum_alias::{frunk::{At, Elem, Len}, Const};
+///
+/// type Row = HList![u32, &'static str, bool];
+///
+/// let mut row: Row = hlist![7, "seven", false];
+/// *row.at_mut(Const::<2>) = true;
+///
+/// let _: Len<Row> = Const::<3>;
+/// let name: &Elem<Row, Const<1>> = row.at(Const::<1>);
+/// assert_eq!((*name, *row.at(Const::<2>)), ("seven", true));
+/// ```
+pub type Elem<L, I> = <L as At<I>>::Output;
+
+impl<H, T> At<Const<0>> for HCons<H, T> {
+    type Output = H;
+
+    #[inline]
+    fn at(&self, _: Const<0>) -> &H {
+        &self.head
+    }
+
+    #[inline]
+    fn at_mut(&mut self, _: Const<0>) -> &mut H {
+        &mut self.head
+    }
+
+    #[inline]
+    fn into_at(self, _: Const<0>) -> H {
+        self.head
+    }
+}
+
+macro_rules! impl_at {
+    ($($n:literal),+) => {
+        $(impl<H, T: At<Const<{ $n - 1 }>>> At<Const<$n>> for HCons<H, T> {
+            type Output = T::Output;
+
+            #[inline]
+            fn at(&self, _: Const<$n>) -> &Self::Output {
+                self.tail.at(Const)
+            }
+
+            #[inline]
+            fn at_mut(&mut self, _: Const<$n>) -> &mut Self::Output {
+                self.tail.at_mut(Const)
+            }
+
+            #[inline]
+            fn into_at(self, _: Const<$n>) -> Self::Output {
+                self.tail.into_at(Const)
+            }
+        })+
+    };
+}
+
+with_table!(impl_at);
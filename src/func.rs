@@ -0,0 +1,192 @@
+//! Type-level functions: types implementing [`TypeFn`] for their arguments, so operators can be passed
+//! to other operators, e. g. mapping a list with `AddF<Const<1>>`
+
+use crate::{
+    ops::{CeilQuot, CopySign, DivCeil, Sign, Signum, WithSign},
+    Const, Constify,
+};
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+use typenum::{
+    Abs, AbsVal, Diff, Eq, Gr, GrEq, IsEqual, IsGreater, IsGreaterOrEqual, IsLess, IsLessOrEqual,
+    IsNotEqual, Le, LeEq, Max, Maximum, Min, Minimum, Mod, Negate, NotEq, Prod, Quot, Sum,
+};
+
+/// A function from `Arg` to `Output` at the type level, see [`Apply`]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be applied to `{Arg}`",
+    label = "isn't a type function of this argument"
+)]
+pub trait TypeFn<Arg> {
+    type Output;
+}
+
+/// The result of the type function `F` for `Arg`
+///
+/// ```rust
+/// use typenum_alias::{func::{AddF, Apply, Compose, MulF, NegF}, Const};
+///
+/// let _: Apply<AddF<Const<3>>, Const<4>> = Const::<7>;
+/// let _: Apply<NegF, Const<4>> = Const::<-4>;
+/// // `(x + 1) * 2`
+/// let _: Apply<Compose<AddF<Const<1>>, MulF<Const<2>>>, Const<4>> = Const::<10>;
+/// ```
+pub type Apply<F, Arg> = <F as TypeFn<Arg>>::Output;
+
+/// The identity function
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Id;
+
+impl<Arg> TypeFn<Arg> for Id {
+    type Output = Arg;
+}
+
+/// The function returning `T` for every argument
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConstF<T>(PhantomData<T>);
+
+impl<T, Arg> TypeFn<Arg> for ConstF<T> {
+    type Output = T;
+}
+
+/// `G` applied to the result of `F`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Compose<F, G>(PhantomData<(F, G)>);
+
+impl<F: TypeFn<Arg>, G: TypeFn<F::Output>, Arg> TypeFn<Arg> for Compose<F, G> {
+    type Output = G::Output;
+}
+
+macro_rules! binary_fns {
+    ($($(#[$attr:meta])* $f:ident => $op:ident, $out:ident;)+) => {
+        $($(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $f<R>(PhantomData<R>);
+
+        impl<Arg: $op<R>, R> TypeFn<Arg> for $f<R> {
+            type Output = $out<Arg, R>;
+        })+
+    };
+}
+
+binary_fns! {
+    /// `x + R`
+    AddF => Add, Sum;
+    /// `x - R`
+    SubF => Sub, Diff;
+    /// `x * R`
+    MulF => Mul, Prod;
+    /// `x / R`
+    DivF => Div, Quot;
+    /// `x % R`
+    RemF => Rem, Mod;
+    /// `x / R` rounded up
+    DivCeilF => DivCeil, CeilQuot;
+    /// `max(x, R)`
+    MaxF => Max, Maximum;
+    /// `min(x, R)`
+    MinF => Min, Minimum;
+    /// `|x|` with the sign of `R`
+    CopySignF => CopySign, WithSign;
+    /// `x < R` as `B1` or `B0`
+    IsLessF => IsLess, Le;
+    /// `x <= R` as `B1` or `B0`
+    IsLessOrEqualF => IsLessOrEqual, LeEq;
+    /// `x > R` as `B1` or `B0`
+    IsGreaterF => IsGreater, Gr;
+    /// `x >= R` as `B1` or `B0`
+    IsGreaterOrEqualF => IsGreaterOrEqual, GrEq;
+    /// `x == R` as `B1` or `B0`
+    IsEqualF => IsEqual, Eq;
+    /// `x != R` as `B1` or `B0`
+    IsNotEqualF => IsNotEqual, NotEq;
+}
+
+macro_rules! unary_fns {
+    ($($(#[$attr:meta])* $f:ident => $op:ident, $out:ident;)+) => {
+        $($(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $f;
+
+        impl<Arg: $op> TypeFn<Arg> for $f {
+            type Output = $out<Arg>;
+        })+
+    };
+}
+
+unary_fns! {
+    /// `-x`
+    NegF => Neg, Negate;
+    /// `|x|`
+    AbsF => Abs, AbsVal;
+    /// The sign of `x`
+    SignumF => Signum, Sign;
+}
+
+macro_rules! pair_fns {
+    ($($(#[$attr:meta])* $f:ident => $op:ident, $out:ident;)+) => {
+        $($(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $f;
+
+        impl<L: $op<R>, R> TypeFn<(L, R)> for $f {
+            type Output = $out<L, R>;
+        })+
+    };
+}
+
+// Functions of pairs, e. g. for `list::Fold`
+pair_fns! {
+    /// `L + R` of the pair `(L, R)`
+    SumF => Add, Sum;
+    /// `L - R` of the pair `(L, R)`
+    DiffF => Sub, Diff;
+    /// `L * R` of the pair `(L, R)`
+    ProdF => Mul, Prod;
+    /// `L / R` of the pair `(L, R)`
+    QuotF => Div, Quot;
+    /// `L % R` of the pair `(L, R)`
+    ModF => Rem, Mod;
+    /// `max(L, R)` of the pair `(L, R)`
+    MaximumF => Max, Maximum;
+    /// `min(L, R)` of the pair `(L, R)`
+    MinimumF => Min, Minimum;
+}
+
+/// Applies `F` to `Seed` `Self` times, see [`Iterate`]
+pub trait Repeat<F, Seed> {
+    type Output;
+}
+
+impl<F, Seed> Repeat<F, Seed> for Const<0> {
+    type Output = Seed;
+}
+
+macro_rules! impl_repeat {
+    ($($n:literal),+) => {
+        $(impl<F: TypeFn<Seed>, Seed> Repeat<F, Seed> for Const<$n>
+        where
+            Const<{ $n - 1 }>: Repeat<F, F::Output>,
+        {
+            type Output = <Const<{ $n - 1 }> as Repeat<F, F::Output>>::Output;
+        })+
+    };
+}
+
+with_table!(impl_repeat);
+
+/// The result of applying the type function `F` to `Seed` `N` times, `N` is a non-negative
+/// `Const` or `typenum` integer in the conversion table. `list::Iterations` lists the intermediate results.
+///
+/// ```rust
+/// use typenum_alias::{func::{Iterate, MulF}, Const};
+/// use typenum::P3;
+///
+/// // Doubling 4 times
+/// let _: Iterate<MulF<Const<2>>, Const<1>, Const<4>> = Const::<16>;
+/// let _: Iterate<MulF<Const<2>>, Const<1>, P3> = Const::<8>;
+/// let _: Iterate<MulF<Const<2>>, Const<3>, Const<0>> = Const::<3>;
+/// ```
+pub type Iterate<F, Seed, N> = <Constify<N> as Repeat<F, Seed>>::Output;
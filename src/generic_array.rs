@@ -0,0 +1,436 @@
+//! [`GenericArray`] with `Const` lengths: `Arr<u8, 32>` instead of `GenericArray<u8, U32>`
+
+use crate::{
+    index::ConstIndex, ops::Magnitude, ops::ToUnsigned, Const, Constify, Normalize, ToTypenum,
+    Typenum,
+};
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Sub},
+    slice,
+};
+use generic_array::{
+    sequence::{Concat, Flatten, Split, Unflatten},
+    ArrayLength, GenericArray,
+};
+use typenum::{Diff, NonZero, PartialDiv, PartialQuot, Prod, Quot, Sum, Unsigned};
+
+/// Converts `Const<N>` to the `typenum` length of [`GenericArray`].
+/// Generic code needs only this bound: `fn f<const N: i32>(a: Arr<u8, N>) where Const<N>: ToLength`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` isn't a valid length of `GenericArray`",
+    label = "expected a non-negative `Const` from the conversion table"
+)]
+pub trait ToLength {
+    type Output: ArrayLength;
+}
+
+pub type Length<N> = <N as ToLength>::Output;
+
+impl<const N: i32> ToLength for Const<N>
+where
+    Const<N>: ToTypenum,
+    Typenum<Const<N>>: ToUnsigned,
+    Magnitude<Typenum<Const<N>>>: ArrayLength,
+{
+    type Output = Magnitude<Typenum<Const<N>>>;
+}
+
+/// `GenericArray` of `N` elements. `N` is behind a projection, so it isn't inferred from arguments.
+///
+/// ```rust
+/// use typenum_alias::{generic_array::{Arr, ToLength}, Const};
+///
+/// fn sum<const N: i32>(a: &Arr<u8, N>) -> u32
+/// where
+///     Const<N>: ToLength,
+/// {
+///     a.iter().map(|&x| u32::from(x)).sum()
+/// }
+///
+/// let a = Arr::<u8, 4>::from([1, 2, 3, 4]);
+/// assert_eq!(sum::<4>(&a), 10);
+/// ```
+pub type Arr<T, const N: i32> = ArrOf<T, Const<N>>;
+
+/// `GenericArray` with the length given by a `Const` expression, e. g. `ArrOf<u8, Diff<Const<16>, Const<4>>>`
+pub type ArrOf<T, N> = GenericArray<T, Length<N>>;
+
+/// Splits `arr` at `K`, computing the length of the rest with `Const`.
+///
+/// ```rust
+/// use typenum_alias::{generic_array::{split, Arr, ToArr}, Const};
+///
+/// let block: Arr<u8, 16> = [0; 16].into_arr();
+/// let (head, tail) = split::<4, _, _>(block);
+/// let _: Arr<u8, 12> = tail;
+/// assert_eq!(head.len(), 4);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn split<const K: i32, T, N>(
+    arr: GenericArray<T, N>,
+) -> (Arr<T, K>, ArrOf<T, Diff<Constify<N>, Const<K>>>)
+where
+    N: ArrayLength + Normalize,
+    Const<K>: ToLength,
+    Constify<N>: Sub<Const<K>>,
+    Diff<Constify<N>, Const<K>>: ToLength,
+    GenericArray<T, N>: Split<
+        T,
+        Length<Const<K>>,
+        First = Arr<T, K>,
+        Second = ArrOf<T, Diff<Constify<N>, Const<K>>>,
+    >,
+{
+    arr.split()
+}
+
+/// Concatenates `a` & `b`, computing the length with `Const`.
+///
+/// ```rust
+/// use typenum_alias::{generic_array::{concat, Arr, ToArr}, Const};
+///
+/// let joined: Arr<u8, 12> = concat([1; 4].into_arr(), [2; 8].into_arr());
+/// assert_eq!(joined[3..5], [1, 2]);
+/// ```
+pub fn concat<T, N, M>(
+    a: GenericArray<T, N>,
+    b: GenericArray<T, M>,
+) -> ArrOf<T, Sum<Constify<N>, Constify<M>>>
+where
+    N: ArrayLength + Normalize,
+    M: ArrayLength + Normalize,
+    Constify<N>: Add<Constify<M>>,
+    Sum<Constify<N>, Constify<M>>: ToLength,
+    GenericArray<T, N>:
+        Concat<T, M, Rest = GenericArray<T, M>, Output = ArrOf<T, Sum<Constify<N>, Constify<M>>>>,
+{
+    a.concat(b)
+}
+
+/// Converts `[T; N]` to [`Arr<T, N>`](Arr), by value or by reference. Implemented for lengths in the conversion table.
+/// The other way is `From<GenericArray<T, _>> for [T; N]` of `generic_array`.
+///
+/// ```rust
+/// use typenum_alias::generic_array::{Arr, ToArr};
+///
+/// let mut key = [7u8; 16];
+/// let arr: &Arr<u8, 16> = key.as_arr();
+/// assert_eq!(arr[0], 7);
+/// key.as_arr_mut()[0] = 1;
+///
+/// let arr: Arr<u8, 16> = key.into_arr();
+/// let key: [u8; 16] = arr.into();
+/// assert_eq!(key[..2], [1, 7]);
+/// ```
+pub trait ToArr {
+    type Output;
+
+    fn into_arr(self) -> Self::Output;
+    fn as_arr(&self) -> &Self::Output;
+    fn as_arr_mut(&mut self) -> &mut Self::Output;
+}
+
+macro_rules! impl_to_arr {
+    ($($n:literal),+) => {
+        $(impl<T> ToArr for [T; $n] {
+            type Output = Arr<T, $n>;
+
+            #[inline]
+            fn into_arr(self) -> Self::Output {
+                GenericArray::from_array(self)
+            }
+
+            #[inline]
+            fn as_arr(&self) -> &Self::Output {
+                self.into()
+            }
+
+            #[inline]
+            fn as_arr_mut(&mut self) -> &mut Self::Output {
+                self.into()
+            }
+        })+
+    };
+}
+
+impl_to_arr!(0);
+with_table!(impl_to_arr);
+
+/// Flattens `M` arrays of `N` elements into one of `N * M`, e. g. `[[T; N]; M]` or `Arr<Arr<T, N>, M>`.
+///
+/// ```rust
+/// use typenum_alias::generic_array::{flatten, unflatten, Arr};
+/// use typenum_alias::Const;
+///
+/// let tile: Arr<u8, 6> = flatten([[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(tile[3], 4);
+///
+/// let rows: Arr<Arr<u8, 2>, 3> = unflatten::<Const<2>, _, _>(tile);
+/// assert_eq!(rows[1][0], 3);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn flatten<T, A, O>(nested: O) -> ArrOf<T, Prod<Constify<A::Length>, Constify<O::Length>>>
+where
+    A: ConstArray<T>,
+    O: ConstArray<A>,
+    A::Length: Mul<O::Length>,
+    Prod<A::Length, O::Length>: ArrayLength,
+    Constify<A::Length>: Mul<Constify<O::Length>>,
+    Prod<Constify<A::Length>, Constify<O::Length>>: ToLength,
+    GenericArray<GenericArray<T, A::Length>, O::Length>: Flatten<
+        T,
+        A::Length,
+        O::Length,
+        Output = ArrOf<T, Prod<Constify<A::Length>, Constify<O::Length>>>,
+    >,
+{
+    let nested: GenericArray<GenericArray<T, A::Length>, O::Length> =
+        nested.into_generic().into_iter().map(ConstArray::into_generic).collect();
+    nested.flatten()
+}
+
+/// Splits an array into arrays of `N` elements, its length must be divisible by `N`.
+/// See [`flatten`] for an example.
+#[allow(clippy::type_complexity)]
+pub fn unflatten<N, T, A>(flat: A) -> ArrOf<ArrOf<T, N>, PartialQuot<Constify<A::Length>, N>>
+where
+    N: ToLength,
+    A: ConstArray<T>,
+    A::Length: Div<Length<N>>,
+    Quot<A::Length, Length<N>>: ArrayLength,
+    Constify<A::Length>: PartialDiv<N>,
+    PartialQuot<Constify<A::Length>, N>: ToLength,
+    GenericArray<T, A::Length>: Unflatten<
+        T,
+        A::Length,
+        Length<N>,
+        Output = ArrOf<ArrOf<T, N>, PartialQuot<Constify<A::Length>, N>>,
+    >,
+{
+    flat.into_generic().unflatten()
+}
+
+/// Splits & concatenates plain arrays of table lengths & `GenericArray`s, computing the lengths with `Const`,
+/// since stable Rust can't compute `N + M` of const generics.
+///
+/// ```rust
+/// use typenum_alias::{generic_array::{Arr, ConstArray}, Const};
+///
+/// let (head, tail) = [0u8; 16].split_at_const::<Const<4>>();
+/// let _: Arr<u8, 12> = tail;
+///
+/// let joined: Arr<u8, 5> = [1, 2].concat_const([3, 4, 5]);
+/// assert_eq!(joined.concat_const(head).len(), 9);
+/// ```
+#[allow(clippy::type_complexity)]
+pub trait ConstArray<T>: Sized {
+    /// The `typenum` length
+    type Length: ArrayLength + Normalize;
+
+    fn into_generic(self) -> GenericArray<T, Self::Length>;
+
+    /// Splits at `K`
+    fn split_at_const<K>(self) -> (ArrOf<T, K>, ArrOf<T, Diff<Constify<Self::Length>, K>>)
+    where
+        K: ToLength,
+        Constify<Self::Length>: Sub<K>,
+        Diff<Constify<Self::Length>, K>: ToLength,
+        GenericArray<T, Self::Length>: Split<
+            T,
+            Length<K>,
+            First = ArrOf<T, K>,
+            Second = ArrOf<T, Diff<Constify<Self::Length>, K>>,
+        >,
+    {
+        self.into_generic().split()
+    }
+
+    /// Appends `rest`
+    fn concat_const<R: ConstArray<T>>(
+        self,
+        rest: R,
+    ) -> ArrOf<T, Sum<Constify<Self::Length>, Constify<R::Length>>>
+    where
+        Constify<Self::Length>: Add<Constify<R::Length>>,
+        Sum<Constify<Self::Length>, Constify<R::Length>>: ToLength,
+        GenericArray<T, Self::Length>: Concat<
+            T,
+            R::Length,
+            Rest = GenericArray<T, R::Length>,
+            Output = ArrOf<T, Sum<Constify<Self::Length>, Constify<R::Length>>>,
+        >,
+    {
+        self.into_generic().concat(rest.into_generic())
+    }
+}
+
+impl<T, N: ArrayLength + Normalize> ConstArray<T> for GenericArray<T, N> {
+    type Length = N;
+
+    #[inline]
+    fn into_generic(self) -> Self {
+        self
+    }
+}
+
+macro_rules! impl_const_array {
+    ($($n:literal),+) => {
+        $(impl<T> ConstArray<T> for [T; $n] {
+            type Length = Length<Const<$n>>;
+
+            #[inline]
+            fn into_generic(self) -> Arr<T, $n> {
+                self.into_arr()
+            }
+        })+
+    };
+}
+
+impl_const_array!(0);
+with_table!(impl_const_array);
+
+/// Fixed-size chunks & windows of slices as [`ArrOf<T, N>`](ArrOf), where `N` is a `Const`.
+///
+/// ```rust
+/// use typenum_alias::{generic_array::ConstChunks, Const};
+///
+/// let data = [1, 2, 3, 4, 5];
+/// let mut chunks = data.chunks_const::<Const<2>>();
+/// assert_eq!(chunks.next().map(|c| c[0] + c[1]), Some(3));
+/// assert_eq!(chunks.remainder(), [5]);
+///
+/// let sums: Vec<i32> = data.windows_const::<Const<3>>().map(|w| w.iter().sum()).collect();
+/// assert_eq!(sums, [6, 9, 12]);
+///
+/// let (chunks, rest) = data.as_chunks_const::<Const<2>>();
+/// assert_eq!((chunks.len(), rest), (2, &[5][..]));
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{generic_array::ConstChunks, Const};
+///
+/// // error: `UTerm: NonZero` isn't satisfied, chunks can't be empty
+/// let _ = [1, 2, 3].chunks_const::<Const<0>>();
+/// ```
+pub trait ConstChunks<T> {
+    /// Chunks of `N` elements, the rest is [`ChunksConst::remainder`]
+    fn chunks_const<N: ToLength>(&self) -> ChunksConst<'_, T, N>
+    where
+        Length<N>: NonZero;
+    /// Overlapping windows of `N` elements
+    fn windows_const<N: ToLength>(&self) -> WindowsConst<'_, T, N>
+    where
+        Length<N>: NonZero;
+    /// Splits into chunks of `N` elements & the rest
+    fn as_chunks_const<N: ToLength>(&self) -> (&[ArrOf<T, N>], &[T])
+    where
+        Length<N>: NonZero;
+}
+
+impl<T> ConstChunks<T> for [T] {
+    fn chunks_const<N: ToLength>(&self) -> ChunksConst<'_, T, N>
+    where
+        Length<N>: NonZero,
+    {
+        ChunksConst(self.chunks_exact(Length::<N>::USIZE), PhantomData)
+    }
+
+    fn windows_const<N: ToLength>(&self) -> WindowsConst<'_, T, N>
+    where
+        Length<N>: NonZero,
+    {
+        WindowsConst(self.windows(Length::<N>::USIZE), PhantomData)
+    }
+
+    fn as_chunks_const<N: ToLength>(&self) -> (&[ArrOf<T, N>], &[T])
+    where
+        Length<N>: NonZero,
+    {
+        GenericArray::chunks_from_slice(self)
+    }
+}
+
+/// Iterator of [`ConstChunks::chunks_const`]
+pub struct ChunksConst<'a, T, N>(slice::ChunksExact<'a, T>, PhantomData<N>);
+
+impl<'a, T, N> ChunksConst<'a, T, N> {
+    /// The last elements, which don't fill a chunk
+    #[must_use]
+    pub fn remainder(&self) -> &'a [T] {
+        self.0.remainder()
+    }
+}
+
+/// Iterator of [`ConstChunks::windows_const`]
+pub struct WindowsConst<'a, T, N>(slice::Windows<'a, T>, PhantomData<N>);
+
+macro_rules! impl_const_iter {
+    ($($iter:ident),+) => {
+        $(impl<'a, T, N: ToLength> Iterator for $iter<'a, T, N> {
+            type Item = &'a ArrOf<T, N>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.0.next().map(GenericArray::from_slice)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        impl<T, N: ToLength> DoubleEndedIterator for $iter<'_, T, N> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.0.next_back().map(GenericArray::from_slice)
+            }
+        }
+
+        impl<T, N: ToLength> ExactSizeIterator for $iter<'_, T, N> {})+
+    };
+}
+
+impl_const_iter!(ChunksConst, WindowsConst);
+
+/// Builds an [`Arr`] from elements, its length is `Const` of their number: `carr![1u8, 2, 3]` is `Arr<u8, 3>`.
+///
+/// ```rust
+/// use typenum_alias::{carr, generic_array::Arr};
+///
+/// let a: Arr<u8, 3> = carr![1, 2, 3];
+/// let zeros: Arr<u8, 16> = carr![0; 16];
+/// assert_eq!(a[2] + zeros[15], 3);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{carr, generic_array::Arr};
+///
+/// let a: Arr<u8, 4> = carr![1, 2, 3];
+/// ```
+#[macro_export]
+macro_rules! carr {
+    ($x:expr; $n:expr) => {
+        $crate::generic_array::ToArr::into_arr([$x; $n])
+    };
+    ($($x:expr),* $(,)?) => {
+        $crate::generic_array::ToArr::into_arr([$($x),*])
+    };
+}
+
+// SAFETY: `Len` is `N`, which is the length of the slices
+unsafe impl<T, N: ArrayLength + Normalize> ConstIndex for GenericArray<T, N> {
+    type Item = T;
+    type Len = Constify<N>;
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
@@ -0,0 +1,107 @@
+//! Capacities of `heapless` collections as `Const`, so that they can be computed with the operators
+
+use crate::Const;
+use core::ops::{Add, Deref};
+use typenum::Sum;
+
+/// The capacity of a `heapless` collection as `Const`
+pub trait Capacity {
+    type Output;
+}
+
+pub type CapacityOf<C> = <C as Capacity>::Output;
+
+/// `heapless` collections with the capacity `Self`
+pub trait ToCapacity {
+    type Vec<T>: Default + Extend<T> + Deref<Target = [T]>;
+    type String: Default + core::fmt::Write + Deref<Target = str>;
+}
+
+/// `heapless::Vec` with the capacity `N`, which is a `Const` expression: `Vec<u8, Sum<Const<4>, Const<8>>>`
+pub type Vec<T, N> = <N as ToCapacity>::Vec<T>;
+
+/// `heapless::String` with the capacity `N`, which is a `Const` expression
+pub type String<N> = <N as ToCapacity>::String;
+
+/// The number of characters of `N` in decimal, including the sign: `DecimalWidth<Const<-10>>` is `Const<3>`
+pub trait ToDecimalWidth {
+    type Output;
+}
+
+pub type DecimalWidth<N> = <N as ToDecimalWidth>::Output;
+
+const fn decimal_width(n: i32) -> i32 {
+    let mut width = if n < 0 { 2 } else { 1 };
+    let mut n = n.unsigned_abs() / 10;
+    while n > 0 {
+        width += 1;
+        n /= 10;
+    }
+    width
+}
+
+macro_rules! impl_capacity {
+    ($($n:literal),+) => {
+        $(impl<T> Capacity for ::heapless::Vec<T, $n> {
+            type Output = Const<$n>;
+        }
+
+        impl Capacity for ::heapless::String<$n> {
+            type Output = Const<$n>;
+        }
+
+        impl ToCapacity for Const<$n> {
+            type Vec<T> = ::heapless::Vec<T, $n>;
+            type String = ::heapless::String<$n>;
+        })+
+    };
+}
+
+macro_rules! impl_decimal_width {
+    ($($n:literal),+) => {
+        $(impl ToDecimalWidth for Const<$n> {
+            type Output = Const<{ decimal_width($n) }>;
+        }
+
+        impl ToDecimalWidth for Const<-$n> {
+            type Output = Const<{ decimal_width(-$n) }>;
+        })+
+    };
+}
+
+impl_capacity!(0);
+with_table!(impl_capacity);
+
+impl ToDecimalWidth for Const<0> {
+    type Output = Const<1>;
+}
+
+with_table!(impl_decimal_width);
+
+/// Concatenates `a` & `b` into a `Vec` of the sum of their capacities, so it can't overflow.
+///
+/// ```rust
+/// use typenum_alias::{heapless::{concat, DecimalWidth, String}, Const};
+/// use typenum::Sum;
+/// use core::fmt::Write;
+///
+/// let a = heapless::Vec::<u8, 4>::from_slice(&[1, 2]).unwrap();
+/// let b = heapless::Vec::<u8, 8>::from_slice(&[3]).unwrap();
+/// let joined: heapless::Vec<u8, 12> = concat(&a, &b);
+/// assert_eq!(joined, [1, 2, 3]);
+///
+/// // "id=" & any value up to 16
+/// let mut s = String::<Sum<Const<3>, DecimalWidth<Const<16>>>>::new();
+/// write!(s, "id={}", 16).unwrap();
+/// ```
+pub fn concat<T: Clone, A, B>(a: &A, b: &B) -> Vec<T, Sum<CapacityOf<A>, CapacityOf<B>>>
+where
+    A: Capacity + Deref<Target = [T]>,
+    B: Capacity + Deref<Target = [T]>,
+    CapacityOf<A>: Add<CapacityOf<B>>,
+    Sum<CapacityOf<A>, CapacityOf<B>>: ToCapacity,
+{
+    let mut out = Vec::<T, Sum<CapacityOf<A>, CapacityOf<B>>>::default();
+    out.extend(a.iter().chain(b.iter()).cloned());
+    out
+}
@@ -0,0 +1,41 @@
+//! [`Array`] of `hybrid_array` with `Const` sizes: `Arr<u8, 32>` instead of `Array<u8, U32>`
+
+use crate::{ops::Magnitude, ops::ToUnsigned, Const, Constify, ToTypenum, Typenum};
+use hybrid_array::{Array, ArraySize, AssocArraySize};
+
+/// Converts `Const<N>` to the `typenum` size of [`Array`].
+/// Generic code needs only this bound: `fn f<const N: i32>(a: Arr<u8, N>) where Const<N>: ToArraySize`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` isn't a valid size of `hybrid_array::Array`",
+    label = "expected a non-negative `Const` from the conversion table, which `hybrid_array` supports"
+)]
+pub trait ToArraySize {
+    type Output: ArraySize;
+}
+
+pub type Size<N> = <N as ToArraySize>::Output;
+
+impl<const N: i32> ToArraySize for Const<N>
+where
+    Const<N>: ToTypenum,
+    Typenum<Const<N>>: ToUnsigned,
+    Magnitude<Typenum<Const<N>>>: ArraySize,
+{
+    type Output = Magnitude<Typenum<Const<N>>>;
+}
+
+/// `Array` of `N` elements. `N` is behind a projection, so it isn't inferred from arguments.
+///
+/// ```rust
+/// use hybrid_array::Array;
+/// use typenum_alias::{hybrid_array::{Arr, ConstLen}, Const};
+///
+/// let key: Arr<u8, 16> = Array([7; 16]);
+/// let _: ConstLen<Arr<u8, 16>> = Const::<16>;
+/// let _: ConstLen<[u8; 8]> = Const::<8>;
+/// assert_eq!(key.len(), 16);
+/// ```
+pub type Arr<T, const N: i32> = Array<T, Size<Const<N>>>;
+
+/// The size of `[T; N]` or `Array` as `Const`
+pub type ConstLen<A> = Constify<<A as AssocArraySize>::Size>;
@@ -0,0 +1,21 @@
+use crate::{dispatch::DynConst, Const, Pretty};
+use bytemuck::{Pod, Zeroable};
+
+/// `Const` & `Pretty` are `repr(C)` & `repr(transparent)` zero-sized types without invariants
+///
+/// ```rust
+/// use typenum_alias::{Const, Pretty};
+///
+/// assert!(bytemuck::bytes_of(&Const::<5>).is_empty());
+/// let _: Const<5> = bytemuck::cast(());
+/// let _: [Const<-3>; 4] = bytemuck::Zeroable::zeroed();
+/// let _: Pretty<typenum::U7> = bytemuck::cast(Const::<7>);
+/// ```
+// SAFETY: zero-sized types without invariants
+unsafe impl<const N: i32> Zeroable for Const<N> {}
+unsafe impl<const N: i32> Pod for Const<N> {}
+unsafe impl<T> Zeroable for Pretty<T> {}
+unsafe impl<T: 'static> Pod for Pretty<T> {}
+
+// SAFETY: 0 is in the conversion table, but other bit patterns may be not, so it isn't `Pod`
+unsafe impl Zeroable for DynConst {}
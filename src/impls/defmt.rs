@@ -0,0 +1,24 @@
+use crate::{dispatch::DynConst, Const};
+use defmt::{Format, Formatter};
+
+/// Prints `Const<N>`, like `Debug`
+///
+/// ```rust
+/// use typenum_alias::{dispatch::DynConst, Const};
+///
+/// fn log<T: defmt::Format>(_: &T) {}
+/// log(&Const::<-3>);
+/// log(&DynConst::from(Const::<3>));
+/// ```
+impl<const N: i32> Format for Const<N> {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "Const<{=i32}>", N);
+    }
+}
+
+/// Prints the value, like `Display`
+impl Format for DynConst {
+    fn format(&self, f: Formatter<'_>) {
+        defmt::write!(f, "{=i32}", self.get());
+    }
+}
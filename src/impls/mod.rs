@@ -0,0 +1,12 @@
+//! Implementations of traits from optional dependencies
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "num-traits")]
+mod num_traits;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "serde")]
+mod serde;
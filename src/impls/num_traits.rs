@@ -0,0 +1,67 @@
+use crate::Const;
+use num_traits::{Bounded, ConstOne, ConstZero, One, Zero};
+
+impl Zero for Const<0> {
+    #[inline]
+    fn zero() -> Self {
+        Self
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        true
+    }
+}
+
+impl ConstZero for Const<0> {
+    const ZERO: Self = Self;
+}
+
+impl One for Const<1> {
+    #[inline]
+    fn one() -> Self {
+        Self
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        true
+    }
+}
+
+impl ConstOne for Const<1> {
+    const ONE: Self = Self;
+}
+
+/// `Const<N>` has the single value `N`, which is both bounds
+///
+/// ```rust
+/// use num_traits::{Bounded, ConstOne, ConstZero, One, Zero};
+/// use typenum_alias::Const;
+///
+/// assert!(Const::<0>::zero().is_zero());
+/// assert!(Const::<1>::one().is_one());
+/// let _: Const<0> = Const::<0>::ZERO;
+/// let _: Const<1> = Const::<1>::ONE;
+/// assert_eq!(Const::<-3>::min_value().value(), -3);
+/// assert_eq!(Const::<-3>::max_value().value(), -3);
+/// ```
+///
+/// ```rust,compile_fail
+/// use num_traits::Zero;
+/// use typenum_alias::Const;
+///
+/// // error: `Zero` isn't implemented for `Const<1>`
+/// let _ = Const::<1>::zero();
+/// ```
+impl<const N: i32> Bounded for Const<N> {
+    #[inline]
+    fn min_value() -> Self {
+        Self
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self
+    }
+}
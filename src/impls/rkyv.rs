@@ -0,0 +1,117 @@
+use crate::{dispatch::DynConst, Const};
+use rkyv::{
+    bytecheck::CheckBytes,
+    rancor::{Fallible, Source},
+    traits::CopyOptimization,
+    Archive, Archived, Deserialize, Place, Portable, Serialize,
+};
+
+// SAFETY: `Const` is zero-sized with the alignment 1, like `()`
+unsafe impl<const N: i32> Portable for Const<N> {}
+
+// SAFETY: `Const` has a single value without any bytes
+unsafe impl<C: Fallible + ?Sized, const N: i32> CheckBytes<C> for Const<N> {
+    #[inline]
+    unsafe fn check_bytes(_: *const Self, _: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+/// Archived as itself, it has no bytes to store
+impl<const N: i32> Archive for Const<N> {
+    // SAFETY: `Const` is zero-sized
+    const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe { CopyOptimization::enable() };
+
+    type Archived = Self;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, (): Self::Resolver, _: Place<Self::Archived>) {}
+}
+
+impl<S: Fallible + ?Sized, const N: i32> Serialize<S> for Const<N> {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized, const N: i32> Deserialize<Const<N>, D> for Const<N> {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Const<N>, D::Error> {
+        Ok(Self)
+    }
+}
+
+/// The archived form of [`DynConst`], validated against the conversion table by `CheckBytes`
+///
+/// ```rust
+/// use rkyv::{rancor::Error, Archived};
+/// use typenum_alias::{dispatch::DynConst, Const};
+///
+/// let value = DynConst::try_from(-7).unwrap();
+/// let bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+/// let archived = rkyv::access::<Archived<DynConst>, Error>(&bytes).unwrap();
+/// assert_eq!(archived.get(), -7);
+/// assert_eq!(rkyv::deserialize::<DynConst, Error>(archived).unwrap(), value);
+///
+/// let bytes = rkyv::to_bytes::<Error>(&Const::<3>).unwrap();
+/// let _: Const<3> = rkyv::from_bytes::<Const<3>, Error>(&bytes).unwrap();
+///
+/// // A value outside of the conversion table fails the validation
+/// let bytes = rkyv::to_bytes::<Error>(&1000_i32).unwrap();
+/// assert!(rkyv::access::<Archived<DynConst>, Error>(&bytes).is_err());
+/// ```
+#[repr(transparent)]
+pub struct ArchivedDynConst(Archived<i32>);
+
+impl ArchivedDynConst {
+    #[must_use]
+    pub fn get(&self) -> i32 {
+        self.0.to_native()
+    }
+}
+
+// SAFETY: `repr(transparent)` over the portable `Archived<i32>`
+unsafe impl Portable for ArchivedDynConst {}
+
+// SAFETY: every bit pattern is a valid `Archived<i32>`, the value is checked additionally
+unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for ArchivedDynConst
+where
+    C::Error: Source,
+{
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees, that `value` is aligned & initialized
+        let value = unsafe { &*value };
+        DynConst::try_from(value.get()).map(drop).map_err(C::Error::new)
+    }
+}
+
+impl Archive for DynConst {
+    type Archived = ArchivedDynConst;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        // SAFETY: `ArchivedDynConst` is `repr(transparent)` over `Archived<i32>`
+        let out = unsafe { out.cast_unchecked::<Archived<i32>>() };
+        self.get().resolve((), out);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for DynConst {
+    #[inline]
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+/// Fails if the archived value is outside of the conversion table, which may happen without validation
+impl<D: Fallible + ?Sized> Deserialize<DynConst, D> for ArchivedDynConst
+where
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<DynConst, D::Error> {
+        DynConst::try_from(self.get()).map_err(D::Error::new)
+    }
+}
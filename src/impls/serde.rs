@@ -0,0 +1,41 @@
+use crate::Const;
+use core::fmt;
+use serde::{
+    de::{Error, Expected, Unexpected},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes the value `N`
+impl<const N: i32> Serialize for Const<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(N)
+    }
+}
+
+/// Accepts only the value `N`
+///
+/// ```rust
+/// use typenum_alias::Const;
+///
+/// assert_eq!(serde_json::to_string(&Const::<-5>).unwrap(), "-5");
+/// let _: Const<-5> = serde_json::from_str("-5").unwrap();
+///
+/// let error = serde_json::from_str::<Const<-5>>("6").unwrap_err();
+/// assert_eq!(error.to_string(), "invalid value: integer `6`, expected -5");
+/// ```
+impl<'de, const N: i32> Deserialize<'de> for Const<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i32::deserialize(deserializer)?;
+        if value == N {
+            Ok(Self)
+        } else {
+            Err(D::Error::invalid_value(Unexpected::Signed(value.into()), &Self))
+        }
+    }
+}
+
+impl<const N: i32> Expected for Const<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{N}")
+    }
+}
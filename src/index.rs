@@ -0,0 +1,111 @@
+//! Element access by `Const` indices, which are checked against the length at compile time
+
+use typenum::Integer;
+
+use crate::{shape::IndexOf, Const, Expand, Typenumify};
+
+/// The value of `I` as `usize`, taken from `Typenumify<I>`, which is what `IndexOf` checks
+#[allow(clippy::cast_sign_loss)] // `IndexOf` proves `I >= 0`
+const fn index<I: Expand>() -> usize
+where
+    Typenumify<I>: Integer,
+{
+    <Typenumify<I> as Integer>::I32 as usize
+}
+
+/// Access to elements by `Const` or `typenum` indices proven less than the length, so without bounds checks.
+/// Implemented for arrays of lengths in the conversion table & `GenericArray` of the `generic-array` feature.
+///
+/// The methods are suffixed by `_const`, since `get` would shadow `<[T]>::get` of arrays.
+///
+/// ```rust
+/// use typenum_alias::{index::ConstIndex, Const};
+///
+/// let mut taps = [1, -2, 3];
+/// assert_eq!(*taps.get_const::<Const<2>>(), 3);
+/// *taps.get_const_mut::<Const<0>>() = 4;
+/// assert_eq!(taps, [4, -2, 3]);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{index::ConstIndex, Const};
+///
+/// // error: the index `Const<3>` is out of the bounds `0..Const<3>`
+/// [1, -2, 3].get_const::<Const<3>>();
+/// ```
+///
+/// The index is read from the same `typenum` integer the bounds check compares,
+/// so a `Normalize` inconsistent with `Expand` can't index out of bounds:
+///
+/// ```rust
+/// use typenum_alias::{index::ConstIndex, Const, Expand, Normalize};
+///
+/// struct Inconsistent;
+///
+/// impl Normalize for Inconsistent {
+///     type Output = Const<100>;
+/// }
+///
+/// impl Expand for Inconsistent {
+///     type Output = typenum::Z0;
+/// }
+///
+/// assert_eq!(*[1, -2, 3].get_const::<Inconsistent>(), 1);
+/// ```
+///
+/// # Safety
+///
+/// The slices of `as_slice` & `as_mut_slice` must have `Len` elements.
+pub unsafe trait ConstIndex {
+    type Item;
+    /// The length as `Const`
+    type Len;
+
+    fn as_slice(&self) -> &[Self::Item];
+    fn as_mut_slice(&mut self) -> &mut [Self::Item];
+
+    /// The element at `I`
+    #[inline]
+    fn get_const<I>(&self) -> &Self::Item
+    where
+        I: IndexOf<Self::Len> + Expand,
+        Typenumify<I>: Integer,
+    {
+        // SAFETY: `I < Len`, which is the length of the slice
+        unsafe { self.as_slice().get_unchecked(index::<I>()) }
+    }
+
+    /// The element at `I` by mutable reference
+    #[inline]
+    fn get_const_mut<I>(&mut self) -> &mut Self::Item
+    where
+        I: IndexOf<Self::Len> + Expand,
+        Typenumify<I>: Integer,
+    {
+        // SAFETY: `I < Len`, which is the length of the slice
+        unsafe { self.as_mut_slice().get_unchecked_mut(index::<I>()) }
+    }
+}
+
+macro_rules! impl_const_index {
+    ($($n:literal),+) => {
+        // SAFETY: `Len` is the length of the array
+        $(unsafe impl<T> ConstIndex for [T; $n] {
+            type Item = T;
+            type Len = Const<$n>;
+
+            #[inline]
+            fn as_slice(&self) -> &[T] {
+                self
+            }
+
+            #[inline]
+            fn as_mut_slice(&mut self) -> &mut [T] {
+                self
+            }
+        })+
+    };
+}
+
+impl_const_index!(0);
+with_table!(impl_const_index);
@@ -0,0 +1,59 @@
+//! Compile-time checks of the output length of HKDF (RFC 5869)
+
+use crate::{
+    ops::{CeilQuot, DivCeil},
+    Expand, Typenumify,
+};
+use core::ops::Mul;
+use typenum::{consts::P255, Cmp, Compare, Equal, Less, Prod};
+
+/// Satisfied when `Self` bytes of output keying material can be expanded by HKDF with a hash of `HashLen` bytes,
+/// RFC 5869 limits them to `255 * HashLen`. Both are `Const` or `typenum` integers.
+///
+/// ```rust
+/// use typenum_alias::{kdf::ExpandBlocks, Const};
+/// use typenum::{P1, P2, P32, P42, P64};
+///
+/// let _: ExpandBlocks<P42, P32> = P2::new();
+/// let _: ExpandBlocks<P64, P64> = P1::new();
+/// let _: ExpandBlocks<Const<12>, Const<8>> = Const::<2>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{kdf::OkmLen, Const};
+/// use typenum::P256;
+///
+/// fn expand<L: OkmLen<H>, H>() {}
+///
+/// // error: `PInt<…>` bytes exceed the HKDF limit of 255 blocks of `Const<1>` bytes
+/// expand::<P256, Const<1>>();
+/// ```
+pub trait OkmLen<HashLen> {
+    /// The number of HMAC blocks of the expansion, `Self / HashLen` rounded up
+    type Blocks;
+}
+
+/// The number of HMAC blocks HKDF computes to expand `Len` bytes with a hash of `HashLen` bytes
+pub type ExpandBlocks<Len, HashLen> = <Len as OkmLen<HashLen>>::Blocks;
+
+/// Implemented by the result of comparing `Len` with `255 * HashLen` if it's within the limit
+#[diagnostic::on_unimplemented(
+    message = "`{Len}` bytes exceed the HKDF limit of 255 blocks of `{HashLen}` bytes",
+    label = "output keying material is too long"
+)]
+pub trait WithinLimit<Len, HashLen> {}
+
+impl<Len, HashLen> WithinLimit<Len, HashLen> for Less {}
+impl<Len, HashLen> WithinLimit<Len, HashLen> for Equal {}
+
+// The limit is compared as `typenum`, since it's usually outside of the conversion table
+impl<Len, HashLen> OkmLen<HashLen> for Len
+where
+    Len: Expand + DivCeil<HashLen>,
+    HashLen: Expand,
+    Typenumify<HashLen>: Mul<P255>,
+    Typenumify<Len>: Cmp<Prod<Typenumify<HashLen>, P255>>,
+    Compare<Typenumify<Len>, Prod<Typenumify<HashLen>, P255>>: WithinLimit<Len, HashLen>,
+{
+    type Blocks = CeilQuot<Len, HashLen>;
+}
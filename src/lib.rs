@@ -4,6 +4,7 @@
 //! `typenum` defines [convenient type aliases](https://docs.rs/typenum/latest/typenum/consts/index.html) for frequently used numbers.
 //! Unfortunately, `rustc` & `rust-analyzer` expand them into their full binary representation, e. g. [`typenum::U10`](https://docs.rs/typenum/latest/typenum/consts/type.U10.html) is expanded to this:
 //! ```rust
+//! # use typenum::{UInt, UTerm, B0, B1};
 //! pub type U10 = UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>;
 //! ```
 //!
@@ -20,36 +21,551 @@
 //! Thanks to this technique, `UInt<UInt<UInt<UInt<UTerm, B1>, B0>, B1>, B0>` becomes just `Const<10_i32>`.
 //! You can shorten it even more to `Const<10>` either by using latest nightly, which already contains the fix
 //! (<https://github.com/rust-lang/rust/pull/99393>), or by waiting for `1.64.0` stable release of Rust.
+//!
+//! ### Conversion table
+//! `Const<N>` is convertible to `typenum` only for `N` in `-16..=16` without features. Bigger tables are enabled by the features
+//! `consts-64`, `consts-256` & `consts-1024`.
+//! The results of operations have to be in the table too, otherwise the error names the operation:
+//! `` the result of `Prod<Const<200>, Const<2>>` is outside of the conversion table ``.
 
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 #![deny(clippy::pedantic)]
 #![allow(clippy::wildcard_imports)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[doc(no_inline)]
 pub use typenum::{consts, operator_aliases, type_operators};
 
-use core::ops::{Add, Div, Mul, Sub};
+#[cfg(feature = "macros")]
+pub use typenum_alias_macros::{
+    const_wrapper, constify, dispatch_table, for_each_const, impl_for_consts, match_const, seq,
+    with_const, ConstLayout, ConstSize, MaxSerializedSize,
+};
+
+pub use assert::Diagnose;
+pub use deep::{Constify, Expand, Normalize, Typenumify};
+pub use error::ConstError;
+pub use pretty::Pretty;
+pub use value::TypenumValue;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::assert::{ConstEq, LessOrEqual, LessThan, Value};
+    pub use paste::paste;
+}
+
+use core::{
+    cmp::Ordering,
+    fmt,
+    num::{NonZeroI32, NonZeroI64, NonZeroU32, NonZeroUsize, TryFromIntError},
+    ops::{Add, Div, Mul, Neg, Range, Rem, Sub},
+};
+use expr::{Divisor, ExactQuot};
+use ops::ToUnsigned;
 use paste::paste;
-use std::ops::Neg;
 use typenum::{
     consts::*, operator_aliases::*, private::InternalMarker, type_operators::*, Diff, Integer,
-    Negate, Prod, Quot, Sum,
+    NInt, Negate, NonZero, PInt, Prod, Quot, Sum, Unsigned,
 };
 
-#[derive(Default, Clone, Copy)]
+#[derive(Default, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        zerocopy::FromBytes,
+        zerocopy::IntoBytes,
+        zerocopy::Immutable,
+        zerocopy::KnownLayout,
+        zerocopy::Unaligned
+    )
+)]
+#[repr(C)]
 pub struct Const<const N: i32>;
 
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+impl<const N: i32> Const<N> {
+    /// Same as `Const::<N>::default()`, but usable in const contexts
+    #[must_use]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns `N`
+    ///
+    /// ```rust
+    /// use typenum_alias::Const;
+    ///
+    /// const FIVE: Const<5> = Const::new();
+    /// assert_eq!(FIVE.value(), 5);
+    /// assert_eq!(Const::<-3>.value(), -3);
+    /// assert_eq!(i32::from(FIVE), 5);
+    /// assert_eq!(i64::from(Const::<-3>), -3);
+    /// assert_eq!(f64::from(FIVE), 5.0);
+    /// ```
+    #[must_use]
+    pub const fn value(self) -> i32 {
+        N
+    }
+
+    /// `0..N`, fails to compile if `N` is negative: `for i in Const::<4>.iter() {}`
+    ///
+    /// ```rust
+    /// use typenum_alias::Const;
+    ///
+    /// assert_eq!(Const::<4>.iter().collect::<Vec<_>>(), [0, 1, 2, 3]);
+    /// assert_eq!(Const::<0>.iter().count(), 0);
+    /// assert_eq!(Const::<3>.iter_i32().collect::<Vec<_>>(), [0, 1, 2]);
+    /// assert_eq!(Const::<-3>.iter_i32().count(), 0);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use typenum_alias::Const;
+    ///
+    /// // error: `N` is negative
+    /// for _ in Const::<-3>.iter() {}
+    /// ```
+    #[must_use]
+    pub const fn iter(self) -> Range<usize> {
+        0..Self::USIZE
+    }
+
+    /// `0..N` as `i32`, empty for negative `N`
+    #[must_use]
+    pub const fn iter_i32(self) -> Range<i32> {
+        0..N
+    }
+
+    /// The `typenum` value of `N`, e. g. for functions taking `typenum` arguments
+    ///
+    /// ```rust
+    /// use typenum_alias::Const;
+    /// use typenum::{N2, P3, Z0};
+    ///
+    /// let _: P3 = Const::<3>.to_typenum();
+    /// let _: Z0 = Const::<0>.to_typenum();
+    /// assert_eq!(Const::<-2>.to_typenum(), N2::new());
+    /// assert_eq!(Const::from_typenum(P3::new()).value(), 3);
+    /// let _: Const<-2> = Const::from_typenum(N2::new());
+    /// let _: Const<0> = Z0::new().into();
+    /// ```
+    #[must_use]
+    pub fn to_typenum(self) -> Typenum<Self>
+    where
+        Self: ToTypenum,
+    {
+        Typenum::<Self>::default()
+    }
+
+    /// The `Const` value of a `typenum` integer: `Const::from_typenum(P3::new())`
+    #[must_use]
+    pub fn from_typenum<T: ToConst<Output = Self>>(_: T) -> Self {
+        Self
+    }
+
+    /// The value `N`, like [`Integer::I32`]
+    pub const VALUE: i32 = N;
+    /// `N` as `i8`, fails to compile if it doesn't fit
+    pub const I8: i8 = {
+        assert!(N >= i8::MIN as i32 && N <= i8::MAX as i32, "`N` doesn't fit into `i8`");
+        N as i8
+    };
+    /// `N` as `i16`, fails to compile if it doesn't fit
+    pub const I16: i16 = {
+        assert!(N >= i16::MIN as i32 && N <= i16::MAX as i32, "`N` doesn't fit into `i16`");
+        N as i16
+    };
+    /// `N` as `i32`
+    pub const I32: i32 = N;
+    /// `N` as `i64`
+    pub const I64: i64 = N as i64;
+    /// `N` as `usize`, e. g. the length of an array: `[0u8; Const::<N>::USIZE]`. Fails to compile if `N` is negative.
+    pub const USIZE: usize = {
+        assert!(N >= 0, "`N` is negative");
+        N as usize
+    };
+    /// `Const<N>`, e. g. for logging
+    ///
+    /// ```rust
+    /// use typenum_alias::Const;
+    ///
+    /// assert_eq!(Const::<0>::NAME, "Const<0>");
+    /// assert_eq!(Const::<-7>::NAME, "Const<-7>");
+    /// assert_eq!(Const::<16>::NAME, "Const<16>");
+    /// assert_eq!(Const::<{ i32::MIN }>::NAME, "Const<-2147483648>");
+    /// ```
+    pub const NAME: &'static str = {
+        let (name, _) = Self::NAME_BUF.0.split_at(Self::NAME_BUF.1);
+        match core::str::from_utf8(name) {
+            Ok(name) => name,
+            Err(_) => unreachable!(),
+        }
+    };
+
+    const NAME_BUF: ([u8; 18], usize) = {
+        let mut buf = *b"Const<\0\0\0\0\0\0\0\0\0\0\0\0";
+        let mut len = 6;
+        if N < 0 {
+            buf[len] = b'-';
+            len += 1;
+        }
+        let digits = len;
+        let mut n = N.unsigned_abs();
+        loop {
+            buf[len] = b'0' + (n % 10) as u8;
+            len += 1;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        // The digits were written in reverse
+        let (mut l, mut r) = (digits, len - 1);
+        while l < r {
+            let digit = buf[l];
+            buf[l] = buf[r];
+            buf[r] = digit;
+            l += 1;
+            r -= 1;
+        }
+        buf[len] = b'>';
+        (buf, len + 1)
+    };
+}
+
+macro_rules! impl_from_const {
+    ($($int:ty),+) => {
+        $(impl<const N: i32> From<Const<N>> for $int {
+            #[inline]
+            fn from(_: Const<N>) -> Self {
+                N.into()
+            }
+        })+
+    };
+}
+
+impl_from_const!(i32, i64, i128, f64);
+
+// `NonZero` & `ToUnsigned` prove `N != 0` & `N > 0`, so the conversions can't fail
+macro_rules! impl_from_const_nonzero {
+    ($($(#[$attr:meta])* $nonzero:ident($int:ty): $($bound:ident),+;)+) => {
+        $($(#[$attr])*
+        impl<const N: i32> From<Const<N>> for $nonzero
+        where
+            Const<N>: ToTypenum,
+            $(Typenum<Const<N>>: $bound,)+
+        {
+            #[inline]
+            #[allow(clippy::cast_sign_loss)]
+            fn from(_: Const<N>) -> Self {
+                match $nonzero::new(N as $int) {
+                    Some(n) => n,
+                    None => unreachable!(),
+                }
+            }
+        })+
+    };
+}
+
+impl_from_const_nonzero! {
+    /// Requires `N != 0`
+    ///
+    /// ```rust
+    /// use core::num::NonZeroI32;
+    /// use typenum_alias::Const;
+    ///
+    /// assert_eq!(NonZeroI32::from(Const::<3>).get(), 3);
+    /// assert_eq!(NonZeroI32::from(Const::<-3>).get(), -3);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use core::num::NonZeroI32;
+    /// use typenum_alias::Const;
+    ///
+    /// // error: `Z0: NonZero` isn't satisfied
+    /// let _ = NonZeroI32::from(Const::<0>);
+    /// ```
+    NonZeroI32(i32): NonZero;
+    /// Requires `N != 0`: `NonZeroI64::from(Const::<-3>)`
+    NonZeroI64(i64): NonZero;
+    /// Requires `N > 0`: `NonZeroU32::from(Const::<3>)`
+    NonZeroU32(u32): NonZero, ToUnsigned;
+    /// Requires `N > 0`
+    ///
+    /// ```rust
+    /// use core::num::NonZeroUsize;
+    /// use typenum_alias::Const;
+    ///
+    /// assert_eq!(NonZeroUsize::from(Const::<5>).get(), 5);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use core::num::NonZeroUsize;
+    /// use typenum_alias::Const;
+    ///
+    /// // error: `NInt<…>` isn't a non-negative `typenum` integer
+    /// let _ = NonZeroUsize::from(Const::<-3>);
+    /// ```
+    NonZeroUsize(usize): NonZero, ToUnsigned;
+}
+
+/// Fails for negative `N`
+///
+/// ```rust
+/// use typenum_alias::Const;
+///
+/// assert_eq!(usize::try_from(Const::<5>), Ok(5));
+/// assert!(usize::try_from(Const::<-1>).is_err());
+/// ```
+impl<const N: i32> TryFrom<Const<N>> for usize {
+    type Error = TryFromIntError;
+
+    #[inline]
+    fn try_from(_: Const<N>) -> Result<Self, Self::Error> {
+        usize::try_from(N)
+    }
+}
+
+// Runtime validation: succeeds only if the value is `N`
+macro_rules! impl_try_from_int {
+    ($($int:ty),+) => {
+        $(impl<const N: i32> TryFrom<$int> for Const<N> {
+            type Error = ConstError;
+
+            #[allow(clippy::cast_lossless)]
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                if Self == value {
+                    Ok(Self)
+                } else {
+                    Err(ConstError::Mismatch { expected: N, found: value as i128 })
+                }
+            }
+        })+
+    };
+}
+
+impl_try_from_int!(i32, usize);
+
+// Value-level conversions to `typenum` & back, `Typenum<Const<N>>` can't be the `Self` of an impl
+impl From<Const<0>> for Z0 {
+    #[inline]
+    fn from(_: Const<0>) -> Self {
+        Self
+    }
+}
+
+impl From<Z0> for Const<0> {
+    #[inline]
+    fn from(_: Z0) -> Self {
+        Self
+    }
+}
+
+macro_rules! impl_from_typenum {
+    ($($int:ident),+) => {
+        $(impl<U: Unsigned + NonZero, const N: i32> From<Const<N>> for $int<U>
+        where
+            Const<N>: ToTypenum<Output = $int<U>>,
+        {
+            #[inline]
+            fn from(_: Const<N>) -> Self {
+                Self::default()
+            }
+        }
+
+        impl<U: Unsigned + NonZero, const N: i32> From<$int<U>> for Const<N>
+        where
+            $int<U>: ToConst<Output = Const<N>>,
+        {
+            #[inline]
+            fn from(_: $int<U>) -> Self {
+                Self
+            }
+        })+
+    };
+}
+
+impl_from_typenum!(PInt, NInt);
+
+/// Compares the values, so that different `Const`s are comparable too.
+///
+/// ```rust
+/// use typenum_alias::{prelude::*, Const};
+///
+/// assert!(Const::<3> == Const::<3>);
+/// assert!(Const::<3> != Const::<4>);
+/// assert!(Const::<3> < Const::<4>);
+/// assert!(Const::<-1> <= Const::<0>);
+/// assert_eq!(Const::<3>.partial_cmp(&Const::<-3>), Some(core::cmp::Ordering::Greater));
+/// let _: Const<4> = Max::max(Const::<3>, Const::<4>);
+/// ```
+///
+/// Runtime `i32` & `usize` compare with `Const` in both directions, negative `N` is less than any `usize`:
+///
+/// ```rust
+/// use typenum_alias::Const;
+///
+/// assert!(Const::<3> == 3_i32);
+/// assert!(3_usize == Const::<3>);
+/// assert!(Const::<3> != 4_i32);
+/// assert!(4_usize > Const::<3>);
+/// assert!(-5_i32 < Const::<-4>);
+/// assert!(Const::<-1> < 0_usize);
+/// ```
+impl<const L: i32, const R: i32> PartialEq<Const<R>> for Const<L> {
+    #[inline]
+    fn eq(&self, _: &Const<R>) -> bool {
+        L == R
+    }
+}
+
+impl<const N: i32> core::cmp::Eq for Const<N> {}
+
+impl<const L: i32, const R: i32> PartialOrd<Const<R>> for Const<L> {
+    #[inline]
+    fn partial_cmp(&self, _: &Const<R>) -> Option<Ordering> {
+        Some(L.cmp(&R))
+    }
+}
+
+/// `Ord::max` & `typenum::Max::max` share the name, so call `Max::max(a, b)` or `Ord::max(a, b)` when `Max` is in scope
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use typenum_alias::{prelude::*, Const};
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(Const::<3>, "three");
+/// assert_eq!(map.get(&Const::<3>), Some(&"three"));
+/// assert_eq!(Ord::max(Const::<3>, Const::<3>), Const::<3>);
+/// let _: Const<4> = Max::max(Const::<3>, Const::<4>);
+/// ```
+impl<const N: i32> Ord for Const<N> {
+    #[inline]
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+// Comparisons with runtime integers in both directions: `len == Const::<64>`
+macro_rules! impl_cmp_with_int {
+    ($($int:ty => $cmp:expr),+) => {
+        $(impl<const N: i32> PartialEq<$int> for Const<N> {
+            #[inline]
+            fn eq(&self, other: &$int) -> bool {
+                self.partial_cmp(other) == Some(Ordering::Equal)
+            }
+        }
+
+        impl<const N: i32> PartialEq<Const<N>> for $int {
+            #[inline]
+            fn eq(&self, other: &Const<N>) -> bool {
+                other == self
+            }
+        }
+
+        impl<const N: i32> PartialOrd<$int> for Const<N> {
+            #[inline]
+            fn partial_cmp(&self, other: &$int) -> Option<Ordering> {
+                let cmp: fn(i32, &$int) -> Ordering = $cmp;
+                Some(cmp(N, other))
+            }
+        }
+
+        impl<const N: i32> PartialOrd<Const<N>> for $int {
+            #[inline]
+            fn partial_cmp(&self, other: &Const<N>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+        )+
+    };
+}
+
+impl_cmp_with_int! {
+    i32 => |n, other| n.cmp(other),
+    usize => |n, other| usize::try_from(n).map_or(Ordering::Less, |n| n.cmp(other))
+}
+
+/// Prints `Const<N>`, while `Display`, `Binary`, `Octal`, `LowerHex` & `UpperHex` print the value `N`
+///
+/// ```rust
+/// use typenum_alias::Const;
+///
+/// assert_eq!(format!("{:?}", Const::<-3>), "Const<-3>");
+/// assert_eq!(format!("{} {:+}", Const::<-3>, Const::<3>), "-3 +3");
+/// assert_eq!(format!("{:b}/{:o}/{:x}/{:X}", Const::<10>, Const::<10>, Const::<10>, Const::<10>), "1010/12/a/A");
+/// assert_eq!(format!("{:#010b}", Const::<10>), "0b00001010");
+/// ```
+impl<const N: i32> fmt::Debug for Const<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Const<{N}>")
+    }
+}
+
+// Print the value `N` like `i32` does, honoring the flags of the formatter
+macro_rules! impl_fmt_value {
+    ($($tr:ident),+) => {
+        $(impl<const N: i32> fmt::$tr for Const<N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$tr::fmt(&N, f)
+            }
+        })+
+    };
+}
+
+impl_fmt_value!(Display, Binary, Octal, LowerHex, UpperHex);
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be converted to `typenum`",
+    label = "not in the conversion table",
+    note = "`Const<N>` is convertible for `N` in `-16..=16`, or up to 64, 256 & 1024 with the features `consts-64`, `consts-256` & `consts-1024`"
+)]
 pub trait ToTypenum {
     type Output: Integer;
 }
 
 pub type Typenum<N> = <N as ToTypenum>::Output;
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be converted to `Const`",
+    label = "not in the conversion table",
+    note = "the conversion table is `-16..=16`, or up to 64, 256 & 1024 with the features `consts-64`, `consts-256` & `consts-1024`"
+)]
 pub trait ToConst {
     type Output: Default;
 }
 
 pub type Constant<T> = <T as ToConst>::Output;
 
+/// Converts the `typenum` result of the operation `Expr` on `Const`s back to `Const`.
+/// Unlike [`ToConst`], it fails with a single error naming the operation, see [`expr`].
+///
+/// ```rust
+/// use typenum_alias::Const;
+///
+/// let _: Const<16> = Const::<4> * Const::<4>;
+/// let _: Const<-16> = Const::<-12> - Const::<4>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::Const;
+///
+/// // error: the result of `Prod<…>` is outside of the conversion table, 4096 doesn't fit even `consts-1024`
+/// let _ = Const::<16> * Const::<16> * Const::<16>;
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "the result of `{Expr}` is outside of the conversion table",
+    label = "the result isn't convertible to `Const`",
+    note = "the conversion table is `-16..=16`, or up to 64, 256 & 1024 with the features `consts-64`, `consts-256` & `consts-1024`"
+)]
+pub trait ToConstResult<Expr> {
+    type Output: Default;
+}
+
+pub type ConstResult<T, Expr> = <T as ToConstResult<Expr>>::Output;
+
 impl ToTypenum for Const<0> {
     type Output = Z0;
 }
@@ -58,6 +574,10 @@ impl ToConst for Z0 {
     type Output = Const<0>;
 }
 
+impl<E> ToConstResult<E> for Z0 {
+    type Output = Const<0>;
+}
+
 macro_rules! const_conversion {
     ($($num:literal),+) => {
         $(impl ToTypenum for Const<$num> {
@@ -68,12 +588,20 @@ macro_rules! const_conversion {
             type Output = Const<$num>;
         }
 
+        impl<E> ToConstResult<E> for paste!([<P $num>]) {
+            type Output = Const<$num>;
+        }
+
         impl ToTypenum for Const<-$num> {
             type Output = paste!([<N $num>]);
         }
 
         impl ToConst for paste!([<N $num>]) {
             type Output = Const<-$num>;
+        }
+
+        impl<E> ToConstResult<E> for paste!([<N $num>]) {
+            type Output = Const<-$num>;
         })+
     };
 }
@@ -85,9 +613,9 @@ macro_rules! impl_binary_ops_for_const {
             Const<L>: ToTypenum,
             Const<R>: ToTypenum,
             Typenum<Const<L>>: $op<Typenum<Const<R>>>,
-            $out<Typenum<Const<L>>, Typenum<Const<R>>>: ToConst,
+            $out<Typenum<Const<L>>, Typenum<Const<R>>>: ToConstResult<crate::expr::$out<Const<L>, Const<R>>>,
         {
-            type Output = Constant<$out<Typenum<Const<L>>, Typenum<Const<R>>>>;
+            type Output = ConstResult<$out<Typenum<Const<L>>, Typenum<Const<R>>>, crate::expr::$out<Const<L>, Const<R>>>;
 
             #[inline]
             fn $fn(self, _: Const<R>) -> Self::Output {
@@ -102,9 +630,30 @@ macro_rules! impl_binary_ops_for_const {
             Const<L>: ToTypenum,
             Const<R>: ToTypenum,
             Typenum<Const<L>>: $op<Typenum<Const<R>>>,
-            $out<Typenum<Const<L>>, Typenum<Const<R>>>: ToConst,
+            $out<Typenum<Const<L>>, Typenum<Const<R>>>: ToConstResult<crate::expr::$out<Const<L>, Const<R>>>,
+        {
+            type Output = ConstResult<$out<Typenum<Const<L>>, Typenum<Const<R>>>, crate::expr::$out<Const<L>, Const<R>>>;
+        })+
+    };
+}
+
+// The divisor goes through `NonZeroDivisor`, so that division by zero fails only there
+macro_rules! impl_division_ops_for_const {
+    ($(($op:ident, $out:ident $(, $fn:ident)?),)+) => {
+        $(impl<const L: i32, const R: i32> $op<Const<R>> for Const<L>
+        where
+            Const<L>: ToTypenum,
+            Const<R>: ToTypenum,
+            Typenum<Const<R>>: expr::NonZeroDivisor<Const<L>>,
+            Typenum<Const<L>>: $op<Divisor<Const<L>, Const<R>>>,
+            $out<Typenum<Const<L>>, Divisor<Const<L>, Const<R>>>: ToConstResult<crate::expr::$out<Const<L>, Const<R>>>,
         {
-            type Output = Constant<$out<Typenum<Const<L>>, Typenum<Const<R>>>>;
+            type Output = ConstResult<$out<Typenum<Const<L>>, Divisor<Const<L>, Const<R>>>, crate::expr::$out<Const<L>, Const<R>>>;
+
+            $(#[inline]
+            fn $fn(self, _: Const<R>) -> Self::Output {
+                Self::Output::default()
+            })?
         })+
     };
 }
@@ -115,9 +664,9 @@ macro_rules! impl_unary_ops_for_const {
         where
             Const<N>: ToTypenum,
             Typenum<Const<N>>: $op,
-            $out<Typenum<Const<N>>>: ToConst,
+            $out<Typenum<Const<N>>>: ToConstResult<crate::expr::$out<Const<N>>>,
         {
-            type Output = Constant<$out<Typenum<Const<N>>>>;
+            type Output = ConstResult<$out<Typenum<Const<N>>>, crate::expr::$out<Const<N>>>;
 
             #[inline]
             fn $fn(self) -> Self::Output {
@@ -130,18 +679,82 @@ macro_rules! impl_unary_ops_for_const {
         where
             Const<N>: ToTypenum,
             Typenum<Const<N>>: $op,
-            $out<Typenum<Const<N>>>: ToConst,
+            $out<Typenum<Const<N>>>: ToConstResult<crate::expr::$out<Const<N>>>,
         {
-            type Output = Constant<$out<Typenum<Const<N>>>>;
+            type Output = ConstResult<$out<Typenum<Const<N>>>, crate::expr::$out<Const<N>>>;
         })+
     };
 }
 
-// TODO: use build.rs to generate this
-// TODO: put different ranges under feature gates
-const_conversion! {
-    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16
-}
+// Defines `TABLE_MAX` & `with_table!`, which passes the numbers of the conversion table to a macro
+include!(concat!(env!("OUT_DIR"), "/table.rs"));
+
+#[cfg(feature = "aead")]
+pub mod aead;
+mod alias;
+pub mod aliases;
+mod assert;
+pub mod audio;
+#[cfg(feature = "bitmaps")]
+pub mod bitmaps;
+#[cfg(feature = "bits")]
+pub mod bits;
+#[cfg(feature = "block-buffer")]
+pub mod block_buffer;
+mod bounds;
+mod calc;
+pub mod clock;
+pub mod conv;
+#[cfg(feature = "crypto-common")]
+pub mod crypto_common;
+mod deep;
+#[cfg(feature = "digest")]
+pub mod digest;
+#[cfg(feature = "dimensioned")]
+pub mod dimensioned;
+pub mod dispatch;
+#[cfg(feature = "elliptic-curve")]
+pub mod elliptic_curve;
+pub mod encoding;
+mod error;
+pub mod expr;
+pub mod fft;
+#[cfg(feature = "frunk")]
+pub mod frunk;
+pub mod func;
+#[cfg(feature = "generic-array")]
+pub mod generic_array;
+#[cfg(feature = "heapless")]
+pub mod heapless;
+#[cfg(feature = "hybrid-array")]
+pub mod hybrid_array;
+mod impls;
+pub mod index;
+pub mod kdf;
+pub mod list;
+pub mod memory;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+pub mod net;
+#[cfg(feature = "alloc")]
+pub mod normalize;
+pub mod ops;
+pub mod padding;
+pub mod pixel;
+pub mod prelude;
+pub mod pretty;
+pub mod ring;
+pub mod shape;
+#[cfg(feature = "simd")]
+pub mod simd;
+pub mod size;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+#[cfg(feature = "uom")]
+pub mod uom;
+mod value;
+
+with_table!(const_conversion);
 
 // Binary ops ---------------------------------------------
 
@@ -149,10 +762,56 @@ impl_binary_ops_for_const! {
     (Add, Sum,  add),
     (Sub, Diff, sub),
     (Mul, Prod, mul),
-    (Div, Quot, div),
     (Max, Maximum, max),
     (Min, Minimum, min),
-    (PartialDiv, PartialQuot, partial_div),
+}
+
+impl_division_ops_for_const! {
+    (Div, Quot, div),
+    (Rem, Mod, rem),
+}
+
+/// Division, which compiles only if `R` divides `L`
+///
+/// ```rust
+/// use typenum_alias::Const;
+/// use typenum::{PartialDiv, PartialQuot};
+///
+/// let _: Const<-4> = Const::<12>.partial_div(Const::<-3>);
+/// let _: PartialQuot<Const<0>, Const<5>> = Const::<0>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::Const;
+/// use typenum::PartialDiv;
+///
+/// // error: `Const<12>` isn't divisible by `Const<5>`
+/// let _ = Const::<12>.partial_div(Const::<5>);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::Const;
+/// use typenum::PartialDiv;
+///
+/// // error: attempt to divide `Const<12>` by zero
+/// let _ = Const::<12>.partial_div(Const::<0>);
+/// ```
+// `typenum` checks the remainder itself, but its error doesn't mention the operands
+impl<const L: i32, const R: i32> PartialDiv<Const<R>> for Const<L>
+where
+    Const<L>: ToTypenum,
+    Const<R>: ToTypenum,
+    Typenum<Const<R>>: expr::NonZeroDivisor<Const<L>>,
+    Typenum<Const<L>>: Rem<Divisor<Const<L>, Const<R>>>,
+    Mod<Typenum<Const<L>>, Divisor<Const<L>, Const<R>>>: expr::Divisible<Const<L>, Const<R>>,
+    ExactQuot<Const<L>, Const<R>>: ToConstResult<expr::PartialQuot<Const<L>, Const<R>>>,
+{
+    type Output = ConstResult<ExactQuot<Const<L>, Const<R>>, expr::PartialQuot<Const<L>, Const<R>>>;
+
+    #[inline]
+    fn partial_div(self, _: Const<R>) -> Self::Output {
+        Self::Output::default()
+    }
 }
 
 impl_binary_ops_for_const! {
@@ -184,6 +843,4 @@ impl_unary_ops_for_const! {
 
 impl_unary_ops_for_const! {
     (Abs, AbsVal),
-    (Logarithm2, Log2),
-    (SquareRoot, Sqrt),
 }
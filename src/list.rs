@@ -0,0 +1,737 @@
+//! Type-level lists: tuples of `Const` or `typenum` integers, e. g. `(Const<1>, Const<-2>, P3)`
+//!
+//! The list operators like [`Map`] go through `typenum` arrays of the elements, see [`ConsOf`],
+//! so their inputs & outputs are tuples of up to 12 elements.
+
+use crate::{
+    assert::Value,
+    func::{Apply, TypeFn},
+    shape::{CheckedIndex, InBounds},
+    Const, Constify, Expand, Normalize, Typenumify,
+};
+use core::{marker::PhantomData, ops::Add};
+use typenum::{ATerm, Cmp, Compare, Eq, Equal, Greater, IsEqual, Less, Sum, TArr, B0, B1, Z0};
+
+/// The values of a list as an array, also in const contexts.
+///
+/// ```rust
+/// use typenum_alias::{list::ToArray, Const};
+/// use typenum::{Prod, P3};
+///
+/// type Taps = (Const<1>, Prod<Const<-2>, Const<2>>, P3);
+///
+/// const TAPS: [i32; 3] = Taps::ARRAY;
+/// assert_eq!(TAPS, [1, -4, 3]);
+/// assert_eq!(Taps::to_array(), [1, -4, 3]);
+/// ```
+pub trait ToArray {
+    /// `[i32; LEN]`
+    type Array;
+    const LEN: usize;
+    const ARRAY: Self::Array;
+
+    #[must_use]
+    fn to_array() -> Self::Array {
+        Self::ARRAY
+    }
+}
+
+macro_rules! impl_to_array {
+    ($($len:literal => ($($t:ident)*);)+) => {
+        $(impl<$($t: Normalize),*> ToArray for ($($t,)*)
+        where
+            $(Constify<$t>: Value,)*
+        {
+            type Array = [i32; $len];
+            const LEN: usize = $len;
+            const ARRAY: Self::Array = [$(<Constify<$t> as Value>::VALUE),*];
+        })+
+    };
+}
+
+impl_to_array! {
+    0 => ();
+    1 => (A);
+    2 => (A B);
+    3 => (A B C);
+    4 => (A B C D);
+    5 => (A B C D E);
+    6 => (A B C D E F);
+    7 => (A B C D E F G);
+    8 => (A B C D E F G H);
+    9 => (A B C D E F G H I);
+    10 => (A B C D E F G H I J);
+    11 => (A B C D E F G H I J K);
+    12 => (A B C D E F G H I J K L);
+}
+
+/// Converts a `typenum` array to a tuple of `Const`, see [`TupleOf`]
+pub trait FromTArr {
+    type Output;
+}
+
+/// The tuple of `Const` with the elements of a `typenum` array: `TupleOf<tarr![P1, N2]>` is `(Const<1>, Const<-2>)`
+pub type TupleOf<A> = <A as FromTArr>::Output;
+
+/// Converts a tuple of `Const` or `typenum` integers to a `typenum` array, see [`TArrOf`]
+pub trait ToTArr {
+    type Output;
+}
+
+/// The `typenum` array with the elements of a tuple: `TArrOf<(Const<1>, Const<-2>)>` is `tarr![P1, N2]`
+///
+/// ```rust
+/// use core::marker::PhantomData;
+/// use typenum_alias::{list::{TArrOf, TupleOf}, tarr_const, Const};
+/// use typenum::{tarr, N2, P1, P3};
+///
+/// let _: PhantomData<TArrOf<(Const<1>, Const<-2>, P3)>> = PhantomData::<tarr![P1, N2, P3]>;
+/// let _: PhantomData<tarr_const![1, -2, 3]> = PhantomData::<tarr![P1, N2, P3]>;
+/// let _: TupleOf<tarr![P1, N2]> = (Const::<1>, Const::<-2>);
+/// ```
+pub type TArrOf<T> = <T as ToTArr>::Output;
+
+/// Converts a tuple to a `typenum` array of its elements as they are, see [`ConsOf`]
+pub trait ToCons {
+    type Output;
+}
+
+/// The `typenum` array of the elements of a tuple as they are, e. g. `ConsOf<(Const<1>, B1)>` is
+/// `TArr<Const<1>, TArr<B1, ATerm>>`. The list operators work on these arrays.
+pub type ConsOf<T> = <T as ToCons>::Output;
+
+/// Converts a `typenum` array to a tuple of its elements as they are, see [`TupleFrom`]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be converted to a tuple",
+    label = "lists are tuples of up to 12 elements"
+)]
+pub trait FromCons {
+    type Output;
+}
+
+/// The tuple of the elements of a `typenum` array as they are
+pub type TupleFrom<C> = <C as FromCons>::Output;
+
+macro_rules! tarr_ty {
+    () => { ATerm };
+    ($head:ty $(, $tail:ty)*) => { TArr<$head, tarr_ty!($($tail),*)> };
+}
+
+macro_rules! impl_tarr {
+    ($(($($t:ident)*);)+) => {
+        $(impl<$($t: Normalize),*> FromTArr for tarr_ty!($($t),*) {
+            type Output = ($(Constify<$t>,)*);
+        }
+
+        impl<$($t: Expand),*> ToTArr for ($($t,)*) {
+            type Output = tarr_ty!($(Typenumify<$t>),*);
+        }
+
+        impl<$($t),*> FromCons for tarr_ty!($($t),*) {
+            type Output = ($($t,)*);
+        }
+
+        impl<$($t),*> ToCons for ($($t,)*) {
+            type Output = tarr_ty!($($t),*);
+        }
+
+        impl<$($t),*> ListLen for ($($t,)*) {
+            type Output = <tarr_ty!($($t),*) as Count>::Output;
+        })+
+    };
+}
+
+impl_tarr! {
+    ();
+    (A);
+    (A B);
+    (A B C);
+    (A B C D);
+    (A B C D E);
+    (A B C D E F);
+    (A B C D E F G);
+    (A B C D E F G H);
+    (A B C D E F G H I);
+    (A B C D E F G H I J);
+    (A B C D E F G H I J K);
+    (A B C D E F G H I J K L);
+}
+
+/// The `typenum` array of integer constants: `tarr_const![1, -2, 3]` is `tarr![P1, N2, P3]`
+#[macro_export]
+macro_rules! tarr_const {
+    ($($n:expr),* $(,)?) => {
+        $crate::list::TArrOf<($($crate::Const<{ $n }>,)*)>
+    };
+}
+
+/// Applies `F` to each element of a `typenum` array, see [`Map`]
+pub trait MapWith<F> {
+    type Output;
+}
+
+impl<F> MapWith<F> for ATerm {
+    type Output = ATerm;
+}
+
+impl<F: TypeFn<V>, V, A: MapWith<F>> MapWith<F> for TArr<V, A> {
+    type Output = TArr<Apply<F, V>, A::Output>;
+}
+
+/// The list of the results of the type function `F` for each element of `L`
+///
+/// ```rust
+/// use typenum_alias::{func::MulF, list::Map, Const};
+///
+/// type Sizes = (Const<4>, Const<8>, Const<-4>);
+///
+/// let _: Map<MulF<Const<2>>, Sizes> = (Const::<8>, Const::<16>, Const::<-8>);
+/// let _: Map<MulF<Const<2>>, ()> = ();
+/// ```
+pub type Map<F, L> = TupleFrom<<ConsOf<L> as MapWith<F>>::Output>;
+
+/// Reduces a `typenum` array with `F` from `Init`, see [`Fold`]
+pub trait FoldWith<F, Init> {
+    type Output;
+}
+
+impl<F, Init> FoldWith<F, Init> for ATerm {
+    type Output = Init;
+}
+
+impl<F, Init, V, A> FoldWith<F, Init> for TArr<V, A>
+where
+    F: TypeFn<(Init, V)>,
+    A: FoldWith<F, Apply<F, (Init, V)>>,
+{
+    type Output = A::Output;
+}
+
+/// The reduction of `L` from `Init` by the type function `F` of pairs `(accumulator, element)`
+///
+/// ```rust
+/// use core::ops::Add;
+/// use typenum_alias::{func::{MaximumF, SumF, TypeFn}, list::Fold, Const};
+/// use typenum::{Minimum, Min, Sum};
+///
+/// type Sizes = (Const<2>, Const<8>, Const<4>);
+///
+/// let _: Fold<SumF, Const<0>, Sizes> = Const::<14>;
+/// let _: Fold<MaximumF, Const<0>, Sizes> = Const::<8>;
+/// let _: Fold<SumF, Const<7>, ()> = Const::<7>;
+///
+/// // A sum saturating at 10
+/// struct SaturatingSum;
+///
+/// impl<A: Add<X>, X> TypeFn<(A, X)> for SaturatingSum
+/// where
+///     Sum<A, X>: Min<Const<10>>,
+/// {
+///     type Output = Minimum<Sum<A, X>, Const<10>>;
+/// }
+///
+/// let _: Fold<SaturatingSum, Const<0>, Sizes> = Const::<10>;
+/// ```
+pub type Fold<F, Init, L> = <ConsOf<L> as FoldWith<F, Init>>::Output;
+
+/// Prepends `V` to `Rest` if `Self` is `B1`, see [`FilterWith`]
+pub trait Keep<V, Rest> {
+    type Output;
+}
+
+impl<V, Rest> Keep<V, Rest> for B1 {
+    type Output = TArr<V, Rest>;
+}
+
+impl<V, Rest> Keep<V, Rest> for B0 {
+    type Output = Rest;
+}
+
+/// Keeps the elements of a `typenum` array, for which `P` is `B1`, see [`Filter`]
+pub trait FilterWith<P> {
+    type Output;
+}
+
+impl<P> FilterWith<P> for ATerm {
+    type Output = ATerm;
+}
+
+impl<P: TypeFn<V>, V, A: FilterWith<P>> FilterWith<P> for TArr<V, A>
+where
+    Apply<P, V>: Keep<V, A::Output>,
+{
+    type Output = <Apply<P, V> as Keep<V, A::Output>>::Output;
+}
+
+/// The elements of `L`, for which the type function `P` is `B1`, in their order
+///
+/// ```rust
+/// use typenum_alias::{func::{Compose, IsEqualF, IsLessOrEqualF, RemF}, list::Filter, Const};
+///
+/// type Sizes = (Const<4>, Const<6>, Const<3>, Const<16>);
+///
+/// let _: Filter<IsLessOrEqualF<Const<8>>, Sizes> = (Const::<4>, Const::<6>, Const::<3>);
+/// // Even sizes
+/// let _: Filter<Compose<RemF<Const<2>>, IsEqualF<Const<0>>>, Sizes> =
+///     (Const::<4>, Const::<6>, Const::<16>);
+/// let _: Filter<IsLessOrEqualF<Const<0>>, Sizes> = ();
+/// ```
+pub type Filter<P, L> = TupleFrom<<ConsOf<L> as FilterWith<P>>::Output>;
+
+/// Inserts `X` into a sorted `typenum` array before the first element greater or equal, see [`Sorting`]
+pub trait Insert<X> {
+    type Output;
+}
+
+impl<X> Insert<X> for ATerm {
+    type Output = TArr<X, ATerm>;
+}
+
+impl<X: Cmp<V>, V, A> Insert<X> for TArr<V, A>
+where
+    Compare<X, V>: InsertBy<X, V, A>,
+{
+    type Output = <Compare<X, V> as InsertBy<X, V, A>>::Output;
+}
+
+/// Inserts `X` before `V` or into `A` by the result of comparing them
+pub trait InsertBy<X, V, A> {
+    type Output;
+}
+
+impl<X, V, A> InsertBy<X, V, A> for Less {
+    type Output = TArr<X, TArr<V, A>>;
+}
+
+impl<X, V, A> InsertBy<X, V, A> for Equal {
+    type Output = TArr<X, TArr<V, A>>;
+}
+
+impl<X, V, A: Insert<X>> InsertBy<X, V, A> for Greater {
+    type Output = TArr<V, A::Output>;
+}
+
+/// Sorts a `typenum` array in ascending order by insertion, see [`Sort`]
+pub trait Sorting {
+    type Output;
+}
+
+impl Sorting for ATerm {
+    type Output = ATerm;
+}
+
+impl<V, A: Sorting> Sorting for TArr<V, A>
+where
+    A::Output: Insert<V>,
+{
+    type Output = <A::Output as Insert<V>>::Output;
+}
+
+/// The elements of `L` in ascending order. The elements are compared with `Cmp`,
+/// so they must be all `Const` or all `typenum` integers.
+///
+/// ```rust
+/// use typenum_alias::{list::Sort, Const};
+///
+/// type Dims = (Const<16>, Const<-1>, Const<8>, Const<8>);
+///
+/// let _: Sort<Dims> = (Const::<-1>, Const::<8>, Const::<8>, Const::<16>);
+/// // Differently ordered lists unify
+/// let _: Sort<(Const<3>, Const<2>)> = Sort::<(Const<2>, Const<3>)>::default();
+/// let _: Sort<()> = ();
+/// ```
+pub type Sort<L> = TupleFrom<<ConsOf<L> as Sorting>::Output>;
+
+/// Appends the `typenum` array `B`, see [`Concat`]
+pub trait Append<B> {
+    type Output;
+}
+
+impl<B> Append<B> for ATerm {
+    type Output = B;
+}
+
+impl<V, A: Append<B>, B> Append<B> for TArr<V, A> {
+    type Output = TArr<V, A::Output>;
+}
+
+/// The elements of `A` followed by the elements of `B`
+///
+/// ```rust
+/// use typenum_alias::{list::{Concat, Reverse, ToArray}, Const};
+///
+/// type Head = (Const<1>, Const<2>);
+/// type Tail = (Const<3>,);
+///
+/// let _: Concat<Head, Tail> = (Const::<1>, Const::<2>, Const::<3>);
+/// let _: Reverse<Concat<Head, Tail>> = (Const::<3>, Const::<2>, Const::<1>);
+/// let _: Reverse<()> = ();
+/// assert_eq!(Concat::<Head, Tail>::LEN, Head::LEN + Tail::LEN);
+/// ```
+pub type Concat<A, B> = TupleFrom<<ConsOf<A> as Append<ConsOf<B>>>::Output>;
+
+/// Prepends the elements of a `typenum` array in reverse order to `Acc`, see [`Reverse`]
+pub trait ReverseOnto<Acc> {
+    type Output;
+}
+
+impl<Acc> ReverseOnto<Acc> for ATerm {
+    type Output = Acc;
+}
+
+impl<V, A: ReverseOnto<TArr<V, Acc>>, Acc> ReverseOnto<Acc> for TArr<V, A> {
+    type Output = A::Output;
+}
+
+/// The elements of `L` in reverse order
+pub type Reverse<L> = TupleFrom<<ConsOf<L> as ReverseOnto<ATerm>>::Output>;
+
+/// The index of an element found by [`PositionOf`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Found<I>(PhantomData<I>);
+
+/// The result of [`PositionOf`] for missing elements
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NotFound;
+
+/// Adds `1` to a found index
+pub trait Shift {
+    type Output;
+}
+
+impl Shift for NotFound {
+    type Output = NotFound;
+}
+
+impl<I: Add<Const<1>>> Shift for Found<I> {
+    type Output = Found<Sum<I, Const<1>>>;
+}
+
+/// Picks the index `0` if `Self` is `B1`, otherwise shifts the index `Rest` in the tail
+pub trait FoundAt<Rest> {
+    type Output;
+}
+
+impl<Rest> FoundAt<Rest> for B1 {
+    type Output = Found<Const<0>>;
+}
+
+impl<Rest: Shift> FoundAt<Rest> for B0 {
+    type Output = Rest::Output;
+}
+
+/// The first index of an element equal to `X` in a `typenum` array, see [`PositionOf`]
+pub trait Position<X> {
+    type Output;
+}
+
+impl<X> Position<X> for ATerm {
+    type Output = NotFound;
+}
+
+impl<X: IsEqual<V>, V, A: Position<X>> Position<X> for TArr<V, A>
+where
+    Eq<X, V>: FoundAt<A::Output>,
+{
+    type Output = <Eq<X, V> as FoundAt<A::Output>>::Output;
+}
+
+/// Converts a result of [`PositionOf`] to `B1` or `B0`
+pub trait IsFound {
+    type Output;
+}
+
+impl<I> IsFound for Found<I> {
+    type Output = B1;
+}
+
+impl IsFound for NotFound {
+    type Output = B0;
+}
+
+/// `Found<Const<I>>` with the first index `I` of an element of `L` equal to `X` or `NotFound`.
+/// The elements are compared with `IsEqual`, so they must be all `Const` or all `typenum` integers.
+///
+/// ```rust
+/// use typenum_alias::{list::{Contains, Found, NotFound, OneOf, PositionOf}, Const};
+/// use typenum::{B0, B1};
+///
+/// type Supported = (Const<4>, Const<6>, Const<8>);
+///
+/// let _: PositionOf<Supported, Const<6>> = Found::<Const<1>>::default();
+/// let _: PositionOf<Supported, Const<5>> = NotFound;
+/// let _: Contains<Supported, Const<8>> = B1;
+/// let _: Contains<Supported, Const<5>> = B0;
+///
+/// fn key<const N: i32>() where Const<N>: OneOf<Supported> {}
+/// key::<6>();
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{list::OneOf, Const};
+///
+/// fn key<const N: i32>() where Const<N>: OneOf<(Const<4>, Const<6>, Const<8>)> {}
+/// // error: `Const<5>` isn't one of `(Const<4>, Const<6>, Const<8>)`
+/// key::<5>();
+/// ```
+pub type PositionOf<L, X> = <ConsOf<L> as Position<X>>::Output;
+
+/// `B1` if `L` has an element equal to `X`, otherwise `B0`
+pub type Contains<L, X> = <PositionOf<L, X> as IsFound>::Output;
+
+/// Implemented by the result of [`PositionOf`] if `X` is found
+#[diagnostic::on_unimplemented(message = "`{X}` isn't one of `{L}`", label = "not in the list")]
+pub trait Member<X, L> {}
+
+impl<X, L, I> Member<X, L> for Found<I> {}
+
+/// Satisfied when `Self` equals an element of the list `L`
+pub trait OneOf<L> {}
+
+impl<X, L> OneOf<L> for X
+where
+    L: ToCons,
+    ConsOf<L>: Position<X>,
+    PositionOf<L, X>: Member<X, L>,
+{
+}
+
+/// The number of elements of a `typenum` array as `Const`
+pub trait Count {
+    type Output;
+}
+
+impl Count for ATerm {
+    type Output = Const<0>;
+}
+
+impl<V, A: Count> Count for TArr<V, A>
+where
+    A::Output: Add<Const<1>>,
+{
+    type Output = Sum<A::Output, Const<1>>;
+}
+
+/// The element of a `typenum` array at the index `I`
+pub trait Nth<I> {
+    type Output;
+}
+
+impl<V, A> Nth<Const<0>> for TArr<V, A> {
+    type Output = V;
+}
+
+macro_rules! impl_nth {
+    ($($n:literal),+) => {
+        $(impl<V, A: Nth<Const<{ $n - 1 }>>> Nth<Const<$n>> for TArr<V, A> {
+            type Output = A::Output;
+        })+
+    };
+}
+
+with_table!(impl_nth);
+
+/// The element of the list `Self` at the index `I`, see [`Get`]
+pub trait GetAt<I> {
+    type Output;
+}
+
+impl<L, I> GetAt<I> for L
+where
+    L: ToCons,
+    L: ListLen,
+    I: Expand,
+    Len<L>: Expand,
+    Typenumify<I>: Cmp<Z0> + Cmp<Typenumify<Len<L>>>,
+    (Compare<Typenumify<I>, Z0>, Compare<Typenumify<I>, Typenumify<Len<L>>>): InBounds<I, Len<L>>,
+    CheckedIndex<I, Len<L>>: Normalize,
+    ConsOf<L>: Nth<Constify<CheckedIndex<I, Len<L>>>>,
+{
+    type Output = <ConsOf<L> as Nth<Constify<CheckedIndex<I, Len<L>>>>>::Output;
+}
+
+/// The element of `L` at the index `I`, a `Const` or `typenum` integer less than the length
+///
+/// ```rust
+/// use typenum_alias::{list::{Get, Sort}, Const};
+/// use typenum::P2;
+///
+/// type Dims = (Const<16>, Const<2>, Const<4>);
+///
+/// let _: Get<Dims, Const<0>> = Const::<16>;
+/// let _: Get<Sort<Dims>, P2> = Const::<16>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{list::Get, Const};
+///
+/// // error: the index `Const<3>` is out of the bounds `0..Const<3>`
+/// let _: Get<(Const<16>, Const<2>, Const<4>), Const<3>>;
+/// ```
+pub type Get<L, I> = <L as GetAt<I>>::Output;
+
+/// The length of a tuple or a `typenum` array as `Const`, see [`Len`]
+pub trait ListLen {
+    type Output;
+}
+
+impl ListLen for ATerm {
+    type Output = Const<0>;
+}
+
+impl<V, A> ListLen for TArr<V, A>
+where
+    Self: Count,
+{
+    type Output = <Self as Count>::Output;
+}
+
+/// The number of elements of the tuple or the `typenum` array `L` as `Const`
+///
+/// ```rust
+/// use typenum_alias::{list::{Concat, Filter, Len}, func::IsGreaterF, Const};
+/// use typenum::{tarr, Sum, P1, P2};
+///
+/// type Sizes = (Const<4>, Const<6>, Const<8>);
+///
+/// let _: Len<Sizes> = Const::<3>;
+/// let _: Len<()> = Const::<0>;
+/// let _: Len<tarr![P1, P2]> = Const::<2>;
+/// let _: Len<Filter<IsGreaterF<Const<5>>, Sizes>> = Const::<2>;
+/// let _: Len<Concat<Sizes, Sizes>> = Sum::<Len<Sizes>, Len<Sizes>>::default();
+/// ```
+pub type Len<L> = <L as ListLen>::Output;
+
+/// The `typenum` array of `Self`, `Self + Step`, … less than `End`, see [`Range`]
+pub trait Steps<End, Step> {
+    type Output;
+}
+
+impl<A: Cmp<End>, End, Step> Steps<End, Step> for A
+where
+    Compare<A, End>: StepBy<A, End, Step>,
+{
+    type Output = <Compare<A, End> as StepBy<A, End, Step>>::Output;
+}
+
+/// Continues [`Steps`] from `A` by the result of comparing it with `End`
+pub trait StepBy<A, End, Step> {
+    type Output;
+}
+
+impl<A, End, Step> StepBy<A, End, Step> for Less
+where
+    A: Add<Step>,
+    Sum<A, Step>: Steps<End, Step>,
+{
+    type Output = TArr<A, <Sum<A, Step> as Steps<End, Step>>::Output>;
+}
+
+impl<A, End, Step> StepBy<A, End, Step> for Equal {
+    type Output = ATerm;
+}
+
+impl<A, End, Step> StepBy<A, End, Step> for Greater {
+    type Output = ATerm;
+}
+
+/// The list `A`, `A + 1`, …, `B - 1` of `Const`s, empty if `A >= B`
+///
+/// ```rust
+/// use typenum_alias::{func::{MulF, SumF}, list::{Fold, Map, Range, RangeStep}, Const};
+///
+/// let _: Range<Const<-1>, Const<3>> = (Const::<-1>, Const::<0>, Const::<1>, Const::<2>);
+/// let _: RangeStep<Const<0>, Const<10>, Const<4>> = (Const::<0>, Const::<4>, Const::<8>);
+/// let _: Range<Const<3>, Const<3>> = ();
+/// // The sum of the first 3 multiples of 2
+/// type Multiples = Map<MulF<Const<2>>, Range<Const<1>, Const<4>>>;
+/// let _: Fold<SumF, Const<0>, Multiples> = Const::<12>;
+/// ```
+pub type Range<A, B> = RangeStep<A, B, Const<1>>;
+
+/// The list `A`, `A + Step`, … of `Const`s less than `B`. `Step` must be positive.
+pub type RangeStep<A, B, Step> = TupleFrom<<A as Steps<B, Step>>::Output>;
+
+/// The `typenum` array of `Seed` & the results of applying `F` to it up to `Self` times, see [`Iterations`]
+pub trait Trace<F, Seed> {
+    type Output;
+}
+
+impl<F, Seed> Trace<F, Seed> for Const<0> {
+    type Output = TArr<Seed, ATerm>;
+}
+
+macro_rules! impl_trace {
+    ($($n:literal),+) => {
+        $(impl<F: TypeFn<Seed>, Seed> Trace<F, Seed> for Const<$n>
+        where
+            Const<{ $n - 1 }>: Trace<F, F::Output>,
+        {
+            type Output = TArr<Seed, <Const<{ $n - 1 }> as Trace<F, F::Output>>::Output>;
+        })+
+    };
+}
+
+with_table!(impl_trace);
+
+/// The list of `Seed`, `F(Seed)`, …, `F` applied `N` times, so `N + 1` elements, the last of which
+/// is `func::Iterate<F, Seed, N>`
+///
+/// ```rust
+/// use typenum_alias::{func::MulF, list::Iterations, Const};
+///
+/// type Doublings = Iterations<MulF<Const<2>>, Const<1>, Const<3>>;
+///
+/// let _: Doublings = (Const::<1>, Const::<2>, Const::<4>, Const::<8>);
+/// ```
+pub type Iterations<F, Seed, N> = TupleFrom<<Constify<N> as Trace<F, Seed>>::Output>;
+
+/// Pairs the elements of two `typenum` arrays of the same length, see [`Zip`]
+pub trait Pairs<B> {
+    type Output;
+}
+
+impl Pairs<ATerm> for ATerm {
+    type Output = ATerm;
+}
+
+impl<V, A: Pairs<B>, W, B> Pairs<TArr<W, B>> for TArr<V, A> {
+    type Output = TArr<(V, W), A::Output>;
+}
+
+/// Implemented by the result of comparing the lengths `LA` & `LB` of zipped lists if they're equal,
+/// outputs `Out`. `Zip` pairs the output, so that a length mismatch is its only error.
+#[diagnostic::on_unimplemented(
+    message = "the lengths `{LA}` & `{LB}` of the zipped lists differ",
+    label = "lists of different lengths"
+)]
+pub trait EqualLen<LA, LB, Out> {
+    type Output;
+}
+
+impl<LA, LB, Out> EqualLen<LA, LB, Out> for Equal {
+    type Output = Out;
+}
+
+/// `B` as a `typenum` array, if it has the length of `A`
+type Checked<A, B> = <Compare<Len<A>, Len<B>> as EqualLen<Len<A>, Len<B>, ConsOf<B>>>::Output;
+
+/// The list of pairs of the elements of `A` & `B` at the same indices
+///
+/// ```rust
+/// use typenum_alias::{list::Zip, Const};
+///
+/// type Sizes = (Const<16>, Const<32>);
+/// type Aligns = (Const<4>, Const<8>);
+///
+/// let _: Zip<Sizes, Aligns> = ((Const::<16>, Const::<4>), (Const::<32>, Const::<8>));
+/// let _: Zip<(), ()> = ();
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{list::Zip, Const};
+///
+/// // error: the lengths `Const<2>` & `Const<1>` of the zipped lists differ
+/// let _: Zip<(Const<16>, Const<32>), (Const<4>,)>;
+/// ```
+pub type Zip<A, B> = TupleFrom<<ConsOf<A> as Pairs<Checked<A, B>>>::Output>;
@@ -0,0 +1,42 @@
+//! Page & cache line counts of allocations as `Const`
+//!
+//! The arguments are `Const` or `typenum` integers, which are computed as `typenum`, so sizes outside of
+//! the conversion table can be given as `typenum` integers, e. g. `P65536`. The results must be in the table.
+
+use crate::{ops::CeilQuot, size::RoundUp, Constify, Typenumify};
+use typenum::{P16384, P4096, P64};
+
+/// 4 KiB, the page size of most architectures
+pub type Page4KiB = P4096;
+
+/// 16 KiB, the page size of Apple silicon
+pub type Page16KiB = P16384;
+
+/// 64 bytes, the cache line size of most `x86_64` & `aarch64` CPUs
+pub type CacheLine64 = P64;
+
+/// The number of pages of `Page` bytes holding `Bytes`
+///
+/// ```rust
+/// use typenum_alias::{memory::{CacheLineAligned, CacheLinesFor, Page16KiB, PagesFor}, Const};
+/// use typenum::{P100, P65536};
+///
+/// let _: PagesFor<P65536> = Const::<16>;
+/// let _: PagesFor<P65536, Page16KiB> = Const::<4>;
+/// let _: PagesFor<Const<1>> = Const::<1>;
+/// let _: CacheLinesFor<P100> = Const::<2>;
+/// let _: CacheLineAligned<Const<5>, Const<8>> = Const::<8>;
+/// ```
+pub type PagesFor<Bytes, Page = Page4KiB> = Constify<CeilQuot<Typenumify<Bytes>, Typenumify<Page>>>;
+
+/// The number of cache lines of `Line` bytes holding `Bytes`
+pub type CacheLinesFor<Bytes, Line = CacheLine64> =
+    Constify<CeilQuot<Typenumify<Bytes>, Typenumify<Line>>>;
+
+/// `Bytes` rounded up to a multiple of the page size `Page`
+pub type PageAligned<Bytes, Page = Page4KiB> =
+    Constify<RoundUp<Typenumify<Bytes>, Typenumify<Page>>>;
+
+/// `Bytes` rounded up to a multiple of the cache line size `Line`, e. g. to avoid false sharing
+pub type CacheLineAligned<Bytes, Line = CacheLine64> =
+    Constify<RoundUp<Typenumify<Bytes>, Typenumify<Line>>>;
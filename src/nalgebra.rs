@@ -0,0 +1,54 @@
+//! Dimensions of `nalgebra` as `Const`: `Matrix<f32, Const<3>, Sum<Const<2>, Const<2>>>`
+
+use crate::Const;
+use nalgebra::{DimName, OMatrix, OVector};
+
+/// Converts `Const<N>` to the dimension `nalgebra::Const<N>`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` isn't a valid dimension of `nalgebra`",
+    label = "expected a non-negative `Const` from the conversion table"
+)]
+pub trait ToDim {
+    type Output: DimName;
+}
+
+pub type Dim<N> = <N as ToDim>::Output;
+
+/// Converts the dimension `nalgebra::Const<N>` to `Const<N>`, `typenum` dimensions are converted by [`Constify`](crate::Constify)
+pub trait FromDim {
+    type Output;
+}
+
+pub type ConstDim<D> = <D as FromDim>::Output;
+
+/// Statically sized matrix, its dimensions are `Const` expressions.
+///
+/// ```rust
+/// use typenum_alias::{nalgebra::{ConstDim, Matrix, Vector}, Const};
+/// use typenum::Sum;
+///
+/// let m = Matrix::<f32, Const<2>, Sum<Const<1>, Const<2>>>::zeros();
+/// let v = Vector::<f32, Const<3>>::repeat(1.0);
+/// assert_eq!((m * v).len(), 2);
+///
+/// let _: ConstDim<nalgebra::Const<4>> = Const::<4>;
+/// ```
+pub type Matrix<T, R, C> = OMatrix<T, Dim<R>, Dim<C>>;
+
+/// Statically sized column vector, its dimension is a `Const` expression
+pub type Vector<T, N> = OVector<T, Dim<N>>;
+
+macro_rules! impl_dim {
+    ($($n:literal),+) => {
+        $(impl ToDim for Const<$n> {
+            type Output = nalgebra::Const<$n>;
+        }
+
+        impl FromDim for nalgebra::Const<$n> {
+            type Output = Const<$n>;
+        })+
+    };
+}
+
+impl_dim!(0);
+with_table!(impl_dim);
@@ -0,0 +1,60 @@
+//! Header sizes of Ethernet, IPv4, IPv6, UDP & TCP & payload sizes of packets
+//!
+//! Headers, MTUs & payloads are usually outside of the conversion table, so they're `typenum` integers:
+//! the value of `UdpPayload<Ipv4Header>` is `<UdpPayload<Ipv4Header> as Integer>::I32`.
+//! The arguments are `Const` or `typenum` integers.
+
+use crate::{size::RoundUp, Typenumify};
+use typenum::{Diff, Prod, Sum, P100, P14, P15, P20, P256, P4, P40, P5, P8};
+
+/// The Ethernet II header: destination, source & type
+pub type EthernetHeader = P14;
+
+/// The IPv4 header without options
+pub type Ipv4Header = P20;
+
+/// The fixed IPv6 header
+pub type Ipv6Header = P40;
+
+/// The UDP header
+pub type UdpHeader = P8;
+
+/// The TCP header without options
+pub type TcpHeader = P20;
+
+/// The MTU of Ethernet, 1500 bytes
+pub type EthernetMtu = Prod<P15, P100>;
+
+/// The minimum MTU of IPv6 links, 1280 bytes
+pub type MinIpv6Mtu = Prod<P5, P256>;
+
+/// The bytes left of `Mtu` after `Headers`
+///
+/// ```rust
+/// use typenum_alias::{
+///     net::{EthernetFrame, Ipv4Header, Ipv6Header, MinIpv6Mtu, Pad4, TcpPayload, UdpPayload},
+///     Const,
+/// };
+/// use typenum::{Integer, P16};
+///
+/// assert_eq!(<UdpPayload<Ipv4Header> as Integer>::I32, 1472);
+/// assert_eq!(<TcpPayload<Ipv6Header, MinIpv6Mtu> as Integer>::I32, 1220);
+/// assert_eq!(<EthernetFrame<UdpPayload<Ipv4Header>> as Integer>::I32, 1486);
+/// let _: Pad4<Const<13>> = P16::new();
+/// ```
+pub type Payload<Mtu, Headers> = Diff<Typenumify<Mtu>, Typenumify<Headers>>;
+
+/// The payload of a UDP datagram in an IP packet with the header `Ip` of at most `Mtu` bytes
+pub type UdpPayload<Ip, Mtu = EthernetMtu> =
+    Payload<Mtu, Sum<Typenumify<Ip>, Typenumify<UdpHeader>>>;
+
+/// The payload of a TCP segment without options in an IP packet with the header `Ip` of at most `Mtu` bytes,
+/// the maximum segment size
+pub type TcpPayload<Ip, Mtu = EthernetMtu> =
+    Payload<Mtu, Sum<Typenumify<Ip>, Typenumify<TcpHeader>>>;
+
+/// The Ethernet frame of a packet of `Packet` bytes, without the frame check sequence
+pub type EthernetFrame<Packet> = Sum<Typenumify<EthernetHeader>, Typenumify<Packet>>;
+
+/// `N` rounded up to a multiple of 4 bytes, the unit of IPv4 & TCP options
+pub type Pad4<N> = RoundUp<Typenumify<N>, P4>;
@@ -0,0 +1,97 @@
+//! Rewrites `typenum` types in text, e. g. compiler output or `core::any::type_name`, to `Const<N>` & `U<N>`.
+//!
+//! `cargo typenum build` from the `cli` feature applies it to the output of `cargo`.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Replaces `UInt`, `PInt` & `NInt` trees with `U<N>` & `Const<N>`. Paths like `typenum::uint::` are removed too.
+/// Trees which aren't complete, e. g. abbreviated with `...`, are left untouched.
+///
+/// ```rust
+/// use typenum_alias::normalize::rewrite;
+///
+/// assert_eq!(
+///     rewrite("expected `PInt<UInt<UInt<UTerm, B1>, B0>>`, found `typenum::uint::UInt<typenum::uint::UTerm, typenum::bit::B1>`"),
+///     "expected `Const<2>`, found `U<1>`",
+/// );
+/// ```
+#[must_use]
+pub fn rewrite(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let boundary =
+            !out.chars().next_back().is_some_and(|p| p.is_alphanumeric() || p == '_' || p == ':');
+        if let Some((tree, tail)) = boundary.then(|| tree(rest)).flatten() {
+            // Writing to a `String` can't fail
+            let _ = match tree {
+                Tree::Unsigned(n) => write!(out, "U<{n}>"),
+                Tree::Integer(n) => match i32::try_from(n) {
+                    Ok(n) => write!(out, "Const<{n}>"),
+                    Err(_) if n < 0 => write!(out, "NInt<U<{}>>", n.unsigned_abs()),
+                    Err(_) => write!(out, "PInt<U<{n}>>"),
+                },
+            };
+            rest = tail;
+        } else {
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    out
+}
+
+enum Tree {
+    Unsigned(u128),
+    Integer(i128),
+}
+
+/// Parses `UInt`, `PInt` or `NInt` at the start of `text`
+fn tree(text: &str) -> Option<(Tree, &str)> {
+    let (name, rest) = name(text)?;
+    match name {
+        "UInt" => unsigned(text).map(|(n, rest)| (Tree::Unsigned(n), rest)),
+        "PInt" | "NInt" => {
+            let rest = rest.strip_prefix('<')?;
+            let (n, rest) = unsigned(rest.trim_start())?;
+            let rest = rest.trim_start().strip_prefix('>')?;
+            let n = i128::try_from(n).ok()?;
+            Some((Tree::Integer(if name == "NInt" { -n } else { n }), rest))
+        }
+        _ => None,
+    }
+}
+
+/// Parses `UTerm` or `UInt<U, B>`
+fn unsigned(text: &str) -> Option<(u128, &str)> {
+    match name(text)? {
+        ("UTerm", rest) => Some((0, rest)),
+        ("UInt", rest) => {
+            let (high, rest) = unsigned(rest.strip_prefix('<')?.trim_start())?;
+            let rest = rest.trim_start().strip_prefix(',')?.trim_start();
+            let (bit, rest) = match name(rest)? {
+                ("B0", rest) => (0, rest),
+                ("B1", rest) => (1, rest),
+                _ => return None,
+            };
+            let rest = rest.trim_start().strip_prefix('>')?;
+            Some((high.checked_mul(2)?.checked_add(bit)?, rest))
+        }
+        _ => None,
+    }
+}
+
+/// Splits off an identifier, skipping its path
+fn name(mut text: &str) -> Option<(&str, &str)> {
+    loop {
+        let len = text.find(|c: char| !c.is_alphanumeric() && c != '_').unwrap_or(text.len());
+        if len == 0 {
+            return None;
+        }
+        match text[len..].strip_prefix("::") {
+            Some(rest) => text = rest,
+            None => return Some(text.split_at(len)),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Type operators which `typenum` doesn't provide, implemented for both `typenum` integers & `Const<N>`.
+
+use crate::{
+    expr::{self, Divisor},
+    Const, ConstResult, Constify, ToConstResult, ToTypenum, Typenum, Typenumify,
+};
+use core::ops::{Add, Div, Neg, Sub};
+use typenum::{
+    consts::{N1, P1, P100, P2, U0},
+    Abs, AbsVal, Bit, Diff, Integer, IsGreaterOrEqual, IsLessOrEqual, Log2, Logarithm2, NInt,
+    Negate, NonZero, PInt, Prod, Quot, Sqrt, SquareRoot, Sum, UInt, UTerm, Unsigned, B1, Z0,
+};
+
+/// Converts a non-negative `typenum` integer to `Unsigned`.
+/// `typenum` implements `Logarithm2` & `SquareRoot` only for `Unsigned`, so `Const<N>` implements them through it.
+///
+/// ```rust
+/// use typenum_alias::Const;
+/// use typenum::{Log2, Sqrt};
+///
+/// let _: Log2<Const<8>> = Const::<3>;
+/// let _: Log2<Const<15>> = Const::<3>;
+/// let _: Sqrt<Const<16>> = Const::<4>;
+/// let _: Sqrt<Const<0>> = Const::<0>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::Const;
+/// use typenum::Sqrt;
+///
+/// // error: the trait bound `Const<-4>: SquareRoot` is not satisfied
+/// let _: Sqrt<Const<-4>>;
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` isn't a non-negative `typenum` integer",
+    label = "expected a non-negative value"
+)]
+pub trait ToUnsigned {
+    type Output: Unsigned;
+}
+
+pub type Magnitude<T> = <T as ToUnsigned>::Output;
+
+impl ToUnsigned for Z0 {
+    type Output = U0;
+}
+
+impl<U: Unsigned + NonZero> ToUnsigned for PInt<U> {
+    type Output = U;
+}
+
+/// Converts a `typenum` unsigned to `Integer`
+pub trait ToInteger {
+    type Output: Integer;
+}
+
+pub type Signed<T> = <T as ToInteger>::Output;
+
+impl ToInteger for UTerm {
+    type Output = Z0;
+}
+
+impl<U: Unsigned, B: Bit> ToInteger for UInt<U, B> {
+    type Output = PInt<UInt<U, B>>;
+}
+
+/// Division rounded towards positive infinity. Defined for non-negative dividends & positive divisors.
+pub trait DivCeil<Rhs = Self> {
+    type Output;
+}
+
+pub type CeilQuot<A, B> = <A as DivCeil<B>>::Output;
+
+// Implemented for each `typenum` integer rather than `L: Integer`, so that `Const<N>` has a single candidate impl,
+// which lets the compiler report the failed bound of `Const<N>`
+macro_rules! impl_div_ceil {
+    ($([$($g:tt)*] $l:ty;)+) => {
+        $(impl<$($g)* R> DivCeil<R> for $l
+        where
+            $l: Add<R>,
+            R: Integer,
+            Sum<$l, R>: Sub<P1>,
+            Diff<Sum<$l, R>, P1>: Div<R>,
+        {
+            type Output = Quot<Diff<Sum<$l, R>, P1>, R>;
+        })+
+    };
+}
+
+impl_div_ceil! {
+    [] Z0;
+    [U: Unsigned + NonZero,] PInt<U>;
+    [U: Unsigned + NonZero,] NInt<U>;
+}
+
+impl_division_ops_for_const! {
+    (DivCeil, CeilQuot),
+}
+
+/// The sign of `Self`: `-1`, `0` or `1`
+pub trait Signum {
+    type Output;
+}
+
+pub type Sign<N> = <N as Signum>::Output;
+
+impl Signum for Z0 {
+    type Output = Z0;
+}
+
+impl<U: Unsigned + NonZero> Signum for PInt<U> {
+    type Output = P1;
+}
+
+impl<U: Unsigned + NonZero> Signum for NInt<U> {
+    type Output = N1;
+}
+
+impl_unary_ops_for_const! {
+    (Signum, Sign),
+}
+
+/// The magnitude of `Self` with the sign of `S`, zero counts as positive.
+///
+/// ```rust
+/// use typenum_alias::{ops::{Sign, WithSign}, Const};
+///
+/// let _: WithSign<Const<-3>, Const<5>> = Const::<3>;
+/// let _: WithSign<Const<3>, Const<-5>> = Const::<-3>;
+/// let _: WithSign<Const<-3>, Const<0>> = Const::<3>;
+/// let _: Sign<Const<-7>> = Const::<-1>;
+/// ```
+pub trait CopySign<S> {
+    type Output;
+}
+
+pub type WithSign<N, S> = <N as CopySign<S>>::Output;
+
+impl<N: Abs> CopySign<Z0> for N {
+    type Output = AbsVal<N>;
+}
+
+impl<N: Abs, U: Unsigned + NonZero> CopySign<PInt<U>> for N {
+    type Output = AbsVal<N>;
+}
+
+impl<N: Abs, U: Unsigned + NonZero> CopySign<NInt<U>> for N
+where
+    AbsVal<N>: Neg,
+{
+    type Output = Negate<AbsVal<N>>;
+}
+
+impl_binary_ops_for_const! {
+    (CopySign, WithSign),
+}
+
+/// Satisfied when `Lo <= Self <= Hi`
+pub trait InRange<Lo, Hi> {}
+
+impl<N, Lo, Hi> InRange<Lo, Hi> for N where
+    N: IsGreaterOrEqual<Lo, Output = B1> + IsLessOrEqual<Hi, Output = B1>
+{
+}
+
+/// Rounds towards negative infinity, see [`Rounding`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Floor;
+
+/// Rounds towards positive infinity, see [`Rounding`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ceil;
+
+/// Rounds to the nearest integer, halves up, see [`Rounding`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Nearest;
+
+/// Division of `typenum` integers rounded by `Self`. Defined for non-negative dividends & positive divisors.
+pub trait Rounding<Num, Den> {
+    type Output;
+}
+
+/// `Num / Den` rounded by `R`
+pub type RoundedQuot<Num, Den, R> = <R as Rounding<Num, Den>>::Output;
+
+impl<Num: Div<Den>, Den> Rounding<Num, Den> for Floor {
+    type Output = Quot<Num, Den>;
+}
+
+impl<Num: DivCeil<Den>, Den> Rounding<Num, Den> for Ceil {
+    type Output = CeilQuot<Num, Den>;
+}
+
+impl<Num, Den> Rounding<Num, Den> for Nearest
+where
+    Den: Div<P2>,
+    Num: Add<Quot<Den, P2>>,
+    Sum<Num, Quot<Den, P2>>: Div<Den>,
+{
+    type Output = Quot<Sum<Num, Quot<Den, P2>>, Den>;
+}
+
+/// `P` percent of `N`, `N * P / 100` rounded by `R`, [`Floor`] by default. Defined for non-negative `N` & `P`.
+///
+/// The arguments are `Const` or `typenum` integers, which are computed as `typenum`.
+/// The result must be in the conversion table.
+///
+/// ```rust
+/// use typenum_alias::{ops::{Ceil, Nearest, Percent}, Const};
+/// use typenum::{Sum, P1000, P20, P33, P35};
+///
+/// // Reserve 20% headroom
+/// type Capacity = Sum<Const<10>, Percent<Const<10>, P20>>;
+/// let _: Capacity = Const::<12>;
+/// let _: Percent<P33, Const<10>> = Const::<3>;
+/// let _: Percent<P33, Const<10>, Ceil> = Const::<4>;
+/// let _: Percent<P35, Const<10>, Nearest> = Const::<4>;
+/// let _: Percent<P1000, Const<1>> = Const::<10>;
+/// ```
+pub type Percent<N, P, R = Floor> =
+    Constify<RoundedQuot<Prod<Typenumify<N>, Typenumify<P>>, P100, R>>;
+
+// `typenum` implements these only for `Unsigned`, so `Const<N>` goes through the magnitude of `N`
+macro_rules! impl_unsigned_ops_for_const {
+    ($(($op:ident, $out:ident),)+) => {
+        $(impl<const N: i32> $op for Const<N>
+        where
+            Const<N>: ToTypenum,
+            Typenum<Const<N>>: ToUnsigned,
+            Magnitude<Typenum<Const<N>>>: $op,
+            $out<Magnitude<Typenum<Const<N>>>>: ToInteger,
+            Signed<$out<Magnitude<Typenum<Const<N>>>>>: ToConstResult<crate::expr::$out<Const<N>>>,
+        {
+            type Output = ConstResult<Signed<$out<Magnitude<Typenum<Const<N>>>>>, crate::expr::$out<Const<N>>>;
+        })+
+    };
+}
+
+impl_unsigned_ops_for_const! {
+    (Logarithm2, Log2),
+    (SquareRoot, Sqrt),
+}
@@ -0,0 +1,63 @@
+//! Lengths after padding to a block size as `Const`, with a selectable rule for lengths that are already aligned
+
+use crate::{
+    ops::{CeilQuot, DivCeil},
+    size::RoundUp,
+    Constify, Expand, Normalize, Typenumify,
+};
+use core::ops::{Add, Div, Mul};
+use typenum::{Prod, Quot, Sum, P1};
+
+/// The rule of PKCS#7, ISO/IEC 7816-4 & ANSI X9.23: an aligned length gets a full block of padding,
+/// so the padding can always be removed
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FullBlock;
+
+/// The rule of zero padding: an aligned length stays as is
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IfNeeded;
+
+/// Computes the length of `N` bytes padded to a multiple of `Block` by the rule `Self`, see [`PaddedLen`]
+pub trait PaddingRule<N, Block> {
+    type Output;
+}
+
+/// The length of `N` bytes padded to a multiple of `Block` by `Rule`, [`FullBlock`] by default.
+///
+/// The arguments are `Const` or `typenum` integers, which are computed as `typenum`.
+/// The result must be in the conversion table. `block_buffer::PaddedLen` of the `block-buffer` feature
+/// computes the same for the padding types of `block-padding`.
+///
+/// ```rust
+/// use typenum_alias::{padding::{IfNeeded, PaddedLen}, Const};
+///
+/// let _: PaddedLen<Const<13>, Const<16>> = Const::<16>;
+/// let _: PaddedLen<Const<8>, Const<4>> = Const::<12>;
+/// let _: PaddedLen<Const<8>, Const<4>, IfNeeded> = Const::<8>;
+/// let _: PaddedLen<Const<0>, Const<8>, IfNeeded> = Const::<0>;
+/// ```
+pub type PaddedLen<N, Block, Rule = FullBlock> = <Rule as PaddingRule<N, Block>>::Output;
+
+impl<N, Block> PaddingRule<N, Block> for FullBlock
+where
+    N: Expand,
+    Block: Expand,
+    Typenumify<N>: Div<Typenumify<Block>>,
+    Quot<Typenumify<N>, Typenumify<Block>>: Add<P1>,
+    Sum<Quot<Typenumify<N>, Typenumify<Block>>, P1>: Mul<Typenumify<Block>>,
+    Prod<Sum<Quot<Typenumify<N>, Typenumify<Block>>, P1>, Typenumify<Block>>: Normalize,
+{
+    type Output =
+        Constify<Prod<Sum<Quot<Typenumify<N>, Typenumify<Block>>, P1>, Typenumify<Block>>>;
+}
+
+impl<N, Block> PaddingRule<N, Block> for IfNeeded
+where
+    N: Expand,
+    Block: Expand,
+    Typenumify<N>: DivCeil<Typenumify<Block>>,
+    CeilQuot<Typenumify<N>, Typenumify<Block>>: Mul<Typenumify<Block>>,
+    RoundUp<Typenumify<N>, Typenumify<Block>>: Normalize,
+{
+    type Output = Constify<RoundUp<Typenumify<N>, Typenumify<Block>>>;
+}
@@ -0,0 +1,69 @@
+//! Bytes per pixel of common pixel formats as `Const` & row pitches of images
+//!
+//! Pitches are usually outside of the conversion table, so they're `typenum` integers: `RowPitch<P1024, Rgba8>`
+//! is `P4096`, its value is `<RowPitch<P1024, Rgba8> as Integer>::I32`. The widths & heights are `Const`
+//! or `typenum` integers.
+
+use crate::{size::RoundUp, Const, Typenumify};
+use typenum::Prod;
+
+/// A pixel format of `Bpp` bytes per pixel, see [`BytesPerPixel`]
+pub trait PixelFormat {
+    type Bpp;
+}
+
+/// The bytes per pixel of the format `F` as `Const`
+pub type BytesPerPixel<F> = <F as PixelFormat>::Bpp;
+
+macro_rules! formats {
+    ($($(#[$attr:meta])* $format:ident => $bpp:literal;)+) => {
+        $($(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $format;
+
+        impl PixelFormat for $format {
+            type Bpp = Const<$bpp>;
+        })+
+    };
+}
+
+formats! {
+    /// 8-bit grayscale or a single 8-bit channel
+    R8 => 1;
+    /// Two 8-bit channels
+    Rg8 => 2;
+    /// 5-bit red, 6-bit green & 5-bit blue
+    Rgb565 => 2;
+    /// 8-bit red, green & blue
+    Rgb8 => 3;
+    /// 8-bit blue, green & red
+    Bgr8 => 3;
+    /// 8-bit red, green, blue & alpha
+    Rgba8 => 4;
+    /// 8-bit blue, green, red & alpha
+    Bgra8 => 4;
+    /// A single 32-bit float channel
+    R32F => 4;
+    /// 16-bit red, green, blue & alpha, integers or half floats
+    Rgba16 => 8;
+    /// 32-bit float red, green, blue & alpha
+    Rgba32F => 16;
+}
+
+/// The bytes of a row of `Width` pixels of the format `F`, rounded up to a multiple of `Align`
+///
+/// ```rust
+/// use typenum_alias::{pixel::{FrameSize, RowPitch, Rgb8, Rgba8}, Const};
+/// use typenum::{Integer, P100, P1024, P256, P300, P33, P4096, P768};
+///
+/// let _: RowPitch<P1024, Rgba8> = P4096::new();
+/// let _: RowPitch<P100, Rgb8, Const<4>> = P300::new();
+/// assert_eq!(<RowPitch<P33, Rgb8, P256> as Integer>::I32, 256);
+/// assert_eq!(<FrameSize<P1024, P768, Rgba8> as Integer>::I32, 1024 * 768 * 4);
+/// ```
+pub type RowPitch<Width, F, Align = Const<1>> =
+    RoundUp<Prod<Typenumify<Width>, Typenumify<BytesPerPixel<F>>>, Typenumify<Align>>;
+
+/// The bytes of an image of `Width` × `Height` pixels of the format `F` with rows aligned to `Align`
+pub type FrameSize<Width, Height, F, Align = Const<1>> =
+    Prod<RowPitch<Width, F, Align>, Typenumify<Height>>;
@@ -0,0 +1,37 @@
+//! Common items in one import: `use typenum_alias::prelude::*;`
+//!
+//! `typenum::Eq` isn't re-exported, since it would shadow `core::cmp::Eq`. Use `IsEqual` instead.
+//!
+//! ```rust
+//! use typenum_alias::prelude::*;
+//!
+//! let _: Sum<Const<3>, Prod<Const<2>, Const<4>>> = Const::<11>;
+//! let _: CeilQuot<Const<5>, Const<2>> = Const::<3>;
+//! let _: Maximum<Const<-1>, Const<2>> = Const::<2>;
+//! let _: Compare<Const<1>, Const<2>> = typenum::Less;
+//! assert_eq!(DynConst::from(Const::<-4>).get(), -4);
+//! assert!(Const::<3> == Const::<3>);
+//! ```
+
+#[doc(no_inline)]
+pub use crate::{
+    assert_align_of, assert_const_le, assert_const_lt, assert_size_of, assert_type_eq, calc,
+    const_bounds, const_test,
+    dispatch::DynConst,
+    ops::{CeilQuot, CopySign, DivCeil, InRange, Sign, Signum, WithSign},
+    reveal_const, Const, Constant, Constify, Diagnose, Expand, Normalize, ToConst, ToTypenum,
+    Typenum, Typenumify,
+};
+
+#[doc(no_inline)]
+pub use typenum::{
+    consts::{False, True},
+    operator_aliases::{
+        AbsVal, Compare, Diff, Gcf, Gr, GrEq, Le, LeEq, Log2, Maximum, Minimum, Mod, Negate, NotEq,
+        PartialQuot, Prod, Quot, Sqrt, Sum,
+    },
+    type_operators::{
+        Abs, Cmp, Gcd, IsEqual, IsGreater, IsGreaterOrEqual, IsLess, IsLessOrEqual, IsNotEqual,
+        Logarithm2, Max, Min, PartialDiv, SquareRoot,
+    },
+};
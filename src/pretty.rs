@@ -0,0 +1,100 @@
+//! Readable formatting of `typenum` types, which `core::any::type_name` prints as binary trees.
+
+use crate::Const;
+use core::{fmt, marker::PhantomData};
+use typenum::{Bit, Integer, NInt, NonZero, PInt, UInt, UTerm, Unsigned, B0, B1, Z0};
+
+/// Formats the value of a `typenum` type
+pub trait Render {
+    /// Writes the value, e. g. `12`
+    ///
+    /// # Errors
+    /// Propagates the errors of the formatter
+    fn value(f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Writes the type in the style of `Const<N>`, e. g. `Const<-12>` or `U<12>`
+    ///
+    /// # Errors
+    /// Propagates the errors of the formatter
+    fn ty(f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl<const N: i32> Render for Const<N> {
+    fn value(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{N}")
+    }
+
+    fn ty(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Const<{N}>")
+    }
+}
+
+macro_rules! impl_render {
+    ($($t:ty, [$($g:tt)*], $value:expr, $ty:literal;)+) => {
+        $(impl<$($g)*> Render for $t {
+            fn value(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", $value)
+            }
+
+            fn ty(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, $ty, $value)
+            }
+        })+
+    };
+}
+
+impl_render! {
+    Z0, [], Z0::I64, "Const<{}>";
+    PInt<U>, [U: Unsigned + NonZero], Self::I64, "Const<{}>";
+    NInt<U>, [U: Unsigned + NonZero], Self::I64, "Const<{}>";
+    UTerm, [], UTerm::U64, "U<{}>";
+    UInt<U, B>, [U: Unsigned, B: Bit], Self::U64, "U<{}>";
+    B0, [], B0::U8, "B{}";
+    B1, [], B1::U8, "B{}";
+}
+
+/// Formats a `typenum` integer, unsigned or bit: `Display` writes the value, `Debug` the type in the style of `Const<N>`.
+/// Values wider than 64 bits are truncated.
+///
+/// ```rust
+/// use typenum_alias::Pretty;
+/// use typenum::{N12, U300};
+///
+/// assert_eq!(format!("{}", Pretty::<U300>::new()), "300");
+/// assert_eq!(format!("{:?}", Pretty::<N12>::new()), "Const<-12>");
+/// ```
+#[repr(transparent)]
+pub struct Pretty<T>(PhantomData<T>);
+
+impl<T> Pretty<T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for Pretty<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Pretty<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Pretty<T> {}
+
+impl<T: Render> fmt::Display for Pretty<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::value(f)
+    }
+}
+
+impl<T: Render> fmt::Debug for Pretty<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::ty(f)
+    }
+}
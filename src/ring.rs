@@ -0,0 +1,51 @@
+//! Ring buffer capacities.
+//!
+//! Indices of a ring buffer with power-of-two capacity wrap around with a single `&`.
+//! `RingCapacity` is implemented only for such capacities, so the trick doesn't need a debug assertion.
+//! Combine it with [`InRange`](crate::ops::InRange) to limit the capacity: `N: RingCapacity + InRange<Const<4>, Const<16>>`.
+
+use crate::{Const, ToTypenum, Typenum};
+use core::ops::Sub;
+use typenum::{Diff, PowerOfTwo};
+
+/// ```rust
+/// use typenum_alias::{ring::{Mask, RingCapacity}, Const};
+///
+/// let _: Mask<Const<8>> = Const::<7>;
+/// assert_eq!(<Const<8> as RingCapacity>::MASK, 7);
+/// assert_eq!(Const::<8>::wrap(13), 5);
+/// assert_eq!(Const::<1>::wrap(13), 0);
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{ring::RingCapacity, Const};
+///
+/// fn buffer<N: RingCapacity>() {}
+/// // error: `Const<12>: RingCapacity` isn't satisfied, 12 isn't a power of two
+/// buffer::<Const<12>>();
+/// ```
+pub trait RingCapacity {
+    /// `N - 1`
+    type Mask;
+
+    const MASK: usize;
+
+    /// Wraps `index` around the capacity
+    #[inline]
+    #[must_use]
+    fn wrap(index: usize) -> usize {
+        index & Self::MASK
+    }
+}
+
+pub type Mask<N> = <N as RingCapacity>::Mask;
+
+impl<const N: i32> RingCapacity for Const<N>
+where
+    Const<N>: ToTypenum + Sub<Const<1>>,
+    Typenum<Const<N>>: PowerOfTwo,
+{
+    type Mask = Diff<Const<N>, Const<1>>;
+
+    const MASK: usize = N.unsigned_abs() as usize - 1;
+}
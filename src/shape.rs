@@ -0,0 +1,266 @@
+//! Matrix shapes with `Const` or `typenum` dimensions, the shapes of transpose, matmul, broadcast & reshape,
+//! strides & offsets of indices
+
+use crate::{Const, Constify, Expand, Normalize, Typenumify};
+use core::{
+    marker::PhantomData,
+    ops::{Add, Mul},
+};
+use typenum::{Cmp, Compare, Equal, Greater, Less, Prod, Sum, Z0};
+
+/// The shape of a matrix of `R` rows & `C` columns
+///
+/// ```rust
+/// use typenum_alias::shape::{Broadcast, Matrix, Product, Reshaped, Transposed};
+///
+/// let _: Transposed<Matrix<2, 3>> = Matrix::<3, 2>::new();
+/// let _: Product<Matrix<2, 3>, Matrix<3, 4>> = Matrix::<2, 4>::new();
+/// let _: Broadcast<Matrix<1, 4>, Matrix<3, 1>> = Matrix::<3, 4>::new();
+/// let _: Reshaped<Matrix<2, 6>, Matrix<3, 4>> = Matrix::<3, 4>::new();
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::shape::{Matrix, Product};
+///
+/// // error: the inner dimensions `Const<3>` & `Const<4>` of the matrix product differ
+/// let _: Product<Matrix<2, 3>, Matrix<4, 2>>;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape<R, C>(PhantomData<(R, C)>);
+
+impl<R, C> Shape<R, C> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R, C> Default for Shape<R, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The shape of a matrix of `R` rows & `C` columns as integer constants
+pub type Matrix<const R: i32, const C: i32> = Shape<Const<R>, Const<C>>;
+
+/// Swaps the rows & columns of a shape, see [`Transposed`]
+pub trait Transpose {
+    type Output;
+}
+
+/// The shape of the transposed matrix
+pub type Transposed<S> = <S as Transpose>::Output;
+
+impl<R, C> Transpose for Shape<R, C> {
+    type Output = Shape<C, R>;
+}
+
+/// Implemented when the columns of the left matrix equal the rows of the right one
+#[diagnostic::on_unimplemented(
+    message = "the inner dimensions `{Self}` & `{Rhs}` of the matrix product differ",
+    label = "the columns of the left matrix must equal the rows of the right one"
+)]
+pub trait InnerDim<Rhs> {}
+
+impl<T> InnerDim<T> for T {}
+
+/// The shape of the product of matrices of the shapes `Self` & `Rhs`, see [`Product`]
+pub trait MatMul<Rhs> {
+    type Output;
+}
+
+/// The shape of `L × R`
+pub type Product<L, R> = <L as MatMul<R>>::Output;
+
+impl<R, K, L, C> MatMul<Shape<L, C>> for Shape<R, K>
+where
+    K: Normalize,
+    L: Normalize,
+    Constify<K>: InnerDim<Constify<L>>,
+{
+    type Output = Shape<R, C>;
+}
+
+/// The dimension of broadcasting `Self` & `Rhs`: equal dimensions stay, `1` is stretched to the other one.
+/// Implemented for `Const` in the conversion table.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` & `{Rhs}` can't be broadcast",
+    label = "the dimensions must be equal or one of them `1`"
+)]
+pub trait BroadcastDim<Rhs> {
+    type Output;
+}
+
+impl<const N: i32> BroadcastDim<Const<N>> for Const<N> {
+    type Output = Self;
+}
+
+// `1` is skipped, since it's covered by the equal dimensions
+macro_rules! impl_broadcast_dim {
+    (@stretch $($n:literal),+) => {
+        $(impl BroadcastDim<Const<$n>> for Const<1> {
+            type Output = Const<$n>;
+        }
+
+        impl BroadcastDim<Const<1>> for Const<$n> {
+            type Output = Self;
+        })+
+    };
+    ($one:literal $(, $n:literal)*) => {
+        impl_broadcast_dim!(@stretch 0 $(, $n)*);
+    };
+}
+
+with_table!(impl_broadcast_dim);
+
+/// The shape of broadcasting matrices of the shapes `Self` & `Rhs` against each other, see [`Broadcast`]
+pub trait BroadcastShape<Rhs> {
+    type Output;
+}
+
+/// The shape of an element-wise operation on matrices of the shapes `L` & `R`
+pub type Broadcast<L, R> = <L as BroadcastShape<R>>::Output;
+
+impl<R1, C1, R2, C2> BroadcastShape<Shape<R2, C2>> for Shape<R1, C1>
+where
+    R1: Normalize,
+    C1: Normalize,
+    R2: Normalize,
+    C2: Normalize,
+    Constify<R1>: BroadcastDim<Constify<R2>>,
+    Constify<C1>: BroadcastDim<Constify<C2>>,
+{
+    type Output = Shape<
+        <Constify<R1> as BroadcastDim<Constify<R2>>>::Output,
+        <Constify<C1> as BroadcastDim<Constify<C2>>>::Output,
+    >;
+}
+
+/// Implemented by the result of comparing the numbers of elements of `From` & `To` if they're equal
+#[diagnostic::on_unimplemented(
+    message = "`{From}` can't be reshaped to `{To}`",
+    label = "the numbers of elements differ"
+)]
+pub trait SameLen<From, To> {}
+
+impl<From, To> SameLen<From, To> for Equal {}
+
+/// Checks that a matrix of the shape `Self` can be reshaped to `To`, see [`Reshaped`]
+pub trait Reshape<To> {
+    type Output;
+}
+
+/// The shape `To` if a matrix of the shape `S` has the same number of elements
+pub type Reshaped<S, To> = <S as Reshape<To>>::Output;
+
+// The numbers of elements are compared as `typenum`, since they're usually outside of the conversion table
+impl<R1, C1, R2, C2> Reshape<Shape<R2, C2>> for Shape<R1, C1>
+where
+    R1: Expand,
+    C1: Expand,
+    R2: Expand,
+    C2: Expand,
+    Typenumify<R1>: Mul<Typenumify<C1>>,
+    Typenumify<R2>: Mul<Typenumify<C2>>,
+    Prod<Typenumify<R1>, Typenumify<C1>>: Cmp<Prod<Typenumify<R2>, Typenumify<C2>>>,
+    Compare<Prod<Typenumify<R1>, Typenumify<C1>>, Prod<Typenumify<R2>, Typenumify<C2>>>:
+        SameLen<Self, Shape<R2, C2>>,
+{
+    type Output = Shape<R2, C2>;
+}
+
+/// The layout of the elements of a matrix, where the rows are contiguous
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RowMajor;
+
+/// The layout of the elements of a matrix, where the columns are contiguous
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ColMajor;
+
+/// The strides of a shape in the layout `L`, see [`Strides`]
+pub trait ToStrides<L> {
+    type Output;
+}
+
+/// The distances between consecutive rows & columns of a matrix of the shape `S` as a tuple of `Const`
+///
+/// ```rust
+/// use typenum_alias::{shape::{ColMajor, Matrix, Offset, Strides}, Const};
+///
+/// let _: Strides<Matrix<3, 4>> = (Const::<4>, Const::<1>);
+/// let _: Strides<Matrix<3, 4>, ColMajor> = (Const::<1>, Const::<3>);
+/// let _: Offset<Matrix<3, 4>, (Const<2>, Const<1>)> = Const::<9>;
+/// let _: Offset<Matrix<3, 4>, (Const<2>, Const<1>), ColMajor> = Const::<5>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{shape::{Matrix, Offset}, Const};
+///
+/// // error: the index `Const<3>` is out of the bounds `0..Const<3>`
+/// let _: Offset<Matrix<3, 4>, (Const<3>, Const<0>)>;
+/// ```
+pub type Strides<S, L = RowMajor> = <S as ToStrides<L>>::Output;
+
+impl<R, C: Normalize> ToStrides<RowMajor> for Shape<R, C> {
+    type Output = (Constify<C>, Const<1>);
+}
+
+impl<R: Normalize, C> ToStrides<ColMajor> for Shape<R, C> {
+    type Output = (Const<1>, Constify<R>);
+}
+
+/// Implemented by the results of comparing `I` with `0` & `D` if `0 <= I < D`
+#[diagnostic::on_unimplemented(
+    message = "the index `{I}` is out of the bounds `0..{D}`",
+    label = "index out of bounds"
+)]
+pub trait InBounds<I, D> {
+    /// `I`
+    type Output;
+}
+
+impl<I, D> InBounds<I, D> for (Greater, Less) {
+    type Output = I;
+}
+
+impl<I, D> InBounds<I, D> for (Equal, Less) {
+    type Output = I;
+}
+
+/// `I` if `0 <= I < D`. Operations on indices use it instead of `I`, so that the bounds check is their only error.
+pub type CheckedIndex<I, D> =
+    <(Compare<Typenumify<I>, Z0>, Compare<Typenumify<I>, Typenumify<D>>) as InBounds<I, D>>::Output;
+
+/// Satisfied when `0 <= Self < D`. Both are `Const` or `typenum` integers.
+pub trait IndexOf<D> {}
+
+impl<I, D> IndexOf<D> for I
+where
+    I: Expand,
+    D: Expand,
+    Typenumify<I>: Cmp<Z0> + Cmp<Typenumify<D>>,
+    (Compare<Typenumify<I>, Z0>, Compare<Typenumify<I>, Typenumify<D>>): InBounds<I, D>,
+{
+}
+
+/// The offset of the index `I` in a shape with the layout `L`, see [`Offset`]
+pub trait ToOffset<I, L> {
+    type Output;
+}
+
+/// The offset of the element at the index `(row, column)` in a matrix of the shape `S` with the layout `L`
+/// as `Const`. The index must be within the shape.
+pub type Offset<S, I, L = RowMajor> = <S as ToOffset<I, L>>::Output;
+
+impl<R, C, I, J, L, SR, SC> ToOffset<(I, J), L> for Shape<R, C>
+where
+    Self: ToStrides<L, Output = (SR, SC)>,
+    I: IndexOf<R> + Normalize,
+    J: IndexOf<C> + Normalize,
+    Constify<I>: Mul<SR>,
+    Constify<J>: Mul<SC>,
+    Prod<Constify<I>, SR>: Add<Prod<Constify<J>, SC>>,
+{
+    type Output = Sum<Prod<Constify<I>, SR>, Prod<Constify<J>, SC>>;
+}
@@ -0,0 +1,52 @@
+//! Lane counts of `core::simd` as `Const`, needs nightly Rust: `Simd<f32, Prod<Const<2>, Const<4>>>`
+
+use crate::Const;
+use core::simd::SimdElement;
+
+/// Converts `Const<N>` to vectors of `N` lanes. Implemented for the supported lane counts, `1..=64`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` isn't a supported lane count",
+    label = "expected a `Const` in `1..=64`"
+)]
+pub trait ToLanes {
+    type Simd<T: SimdElement>;
+}
+
+/// `core::simd::Simd` with `N` lanes, which is a `Const` expression.
+///
+/// ```rust
+/// #![feature(portable_simd)]
+///
+/// use typenum_alias::{simd::{LanesOf, Simd}, Const};
+/// use typenum::Prod;
+///
+/// let v = Simd::<f32, Prod<Const<2>, Const<4>>>::splat(1.0);
+/// let _: LanesOf<core::simd::Simd<f32, 8>> = Const::<8>;
+/// assert_eq!(v.len(), 8);
+/// ```
+pub type Simd<T, N> = <N as ToLanes>::Simd<T>;
+
+/// The number of lanes of a vector as `Const`
+pub trait Lanes {
+    type Output;
+}
+
+pub type LanesOf<V> = <V as Lanes>::Output;
+
+macro_rules! impl_lanes {
+    ($($n:literal),+) => {
+        $(impl ToLanes for Const<$n> {
+            type Simd<T: SimdElement> = core::simd::Simd<T, $n>;
+        }
+
+        impl<T: SimdElement> Lanes for core::simd::Simd<T, $n> {
+            type Output = Const<$n>;
+        })+
+    };
+}
+
+impl_lanes!(
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+    51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64
+);
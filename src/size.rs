@@ -0,0 +1,373 @@
+//! Byte sizes as `Const`: `KiB<Const<16>>` is `Const<16384>`, `BitsToBytes<Const<12>>` is `Const<2>`,
+//! `SizeOf<u64>` is `Const<8>`
+
+use crate::{ops::CeilQuot, Const};
+use core::{
+    marker::PhantomData,
+    mem::{align_of, size_of},
+    ops::{Add, Mul},
+};
+use typenum::{Max, Maximum, Prod, Sum};
+
+macro_rules! prefixes {
+    ($($prefix:ident),+) => {
+        $(#[doc = concat!("The binary prefix `", stringify!($prefix), "` of [`ToBytes`]")]
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $prefix;)+
+    };
+}
+
+prefixes!(Kibi, Mebi, Gibi);
+
+/// Converts `Self` of `Unit` to bytes, so multiplies it by a binary prefix or divides bits by 8 rounding up.
+/// `Self` must be in the conversion table & results outside of `i32` don't compile.
+///
+/// The results of prefixes are usually outside of the conversion table, so they can't be converted to `typenum`.
+///
+/// ```rust
+/// use typenum_alias::{size::{GiB, KiB, MiB}, Const};
+///
+/// let _: KiB<Const<16>> = Const::<16384>;
+/// let _: MiB<Const<-3>> = Const::<-3145728>;
+/// let _: GiB<Const<1>> = Const::<1073741824>;
+/// ```
+///
+/// ```rust,compile_fail
+/// use typenum_alias::{size::GiB, Const};
+///
+/// // error: `Const<2>` of `Gibi` doesn't fit into `i32`
+/// let _: GiB<Const<2>>;
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` of `{Unit}` can't be converted to bytes",
+    label = "overflow of `i32` or outside of the conversion table",
+    note = "`KiB` & `MiB` are defined for the conversion table, `GiB` for `-2..=1`, `BitsToBytes` for its non-negative part"
+)]
+pub trait ToBytes<Unit> {
+    type Output;
+}
+
+/// `N * 1024`
+pub type KiB<N> = <N as ToBytes<Kibi>>::Output;
+
+/// `N * 1024²`
+pub type MiB<N> = <N as ToBytes<Mebi>>::Output;
+
+/// `N * 1024³`
+pub type GiB<N> = <N as ToBytes<Gibi>>::Output;
+
+/// The unit of [`BitsToBytes`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bits;
+
+/// The number of bytes holding `N` bits, `N / 8` rounded up
+///
+/// ```rust
+/// use typenum_alias::{size::{BitsToBytes, BytesToBits}, Const};
+///
+/// let _: BitsToBytes<Const<12>> = Const::<2>;
+/// let _: BitsToBytes<Const<16>> = Const::<2>;
+/// let _: BytesToBits<Const<16>> = Const::<128>;
+/// ```
+pub type BitsToBytes<N> = <N as ToBytes<Bits>>::Output;
+
+/// Converts `Self` bytes to bits, see [`BytesToBits`]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` bytes can't be converted to bits",
+    label = "outside of the conversion table"
+)]
+pub trait ToBits {
+    type Output;
+}
+
+/// `N * 8`. The results may be outside of the conversion table.
+pub type BytesToBits<N> = <N as ToBits>::Output;
+
+// `Const<{ N * 1024 }>` needs `generic_const_exprs`, so the results are computed for each literal
+macro_rules! impl_to_bytes {
+    (Bits; $($n:literal),+) => {
+        $(impl ToBytes<Bits> for Const<$n> {
+            type Output = Const<{ ($n + 7) / 8 }>;
+        })+
+    };
+    (ToBits; $($n:literal),+) => {
+        $(impl ToBits for Const<$n> {
+            type Output = Const<{ $n << 3 }>;
+        })+
+    };
+    ($prefix:ident, $shift:literal; $($n:literal),+) => {
+        $(impl ToBytes<$prefix> for Const<$n> {
+            type Output = Const<{ $n << $shift }>;
+        })+
+    };
+    ($($n:literal),+) => {
+        impl_to_bytes!(Kibi, 10; 0 $(, $n, -$n)+);
+        impl_to_bytes!(Mebi, 20; 0 $(, $n, -$n)+);
+        impl_to_bytes!(Bits; 0 $(, $n)+);
+        impl_to_bytes!(ToBits; 0 $(, $n, -$n)+);
+    };
+}
+
+with_table!(impl_to_bytes);
+impl_to_bytes!(Gibi, 30; 0, 1, -1, -2);
+
+/// `N` rounded up to a multiple of `A`
+pub type RoundUp<N, A> = Prod<CeilQuot<N, A>, A>;
+
+/// The size & alignment of `Self` as `Const`, see [`SizeOf`] & [`AlignOf`].
+///
+/// Implemented for primitives, pointers, arrays in the conversion table, `PhantomData` & `Const`.
+/// `#[derive(ConstLayout)]` of the `macros` feature implements it for `#[repr(C)]` structs.
+#[diagnostic::on_unimplemented(
+    message = "the layout of `{Self}` isn't known at the type level",
+    label = "doesn't implement `ConstLayout`",
+    note = "derive `ConstLayout` for `#[repr(C)]` structs"
+)]
+pub trait ConstLayout {
+    type Size;
+    type Align;
+}
+
+/// The size of `T` in bytes as `Const`
+///
+/// ```rust
+/// use typenum_alias::{size::{AlignOf, RoundUp, SizeOf}, Const};
+/// use typenum::Sum;
+///
+/// // The offset of a `u32` after a `u8` in a `#[repr(C)]` struct
+/// type Offset = RoundUp<SizeOf<u8>, AlignOf<u32>>;
+///
+/// let _: Offset = Const::<4>;
+/// let _: SizeOf<[u16; 3]> = Const::<6>;
+/// let _: Sum<Offset, SizeOf<u32>> = Const::<8>;
+/// ```
+pub type SizeOf<T> = <T as ConstLayout>::Size;
+
+/// The alignment of `T` in bytes as `Const`
+pub type AlignOf<T> = <T as ConstLayout>::Align;
+
+// Sizes of primitives aren't larger than 16 bytes
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+mod primitives {
+    use super::{align_of, size_of, Const, ConstLayout, ConstSize, PhantomData};
+
+    macro_rules! impl_layout {
+        ($([$($g:tt)*] $t:ty => $of:ty;)+) => {
+            $(impl<$($g)*> ConstLayout for $t {
+                type Size = Const<{ size_of::<$of>() as i32 }>;
+                type Align = Const<{ align_of::<$of>() as i32 }>;
+            })+
+        };
+        ($($t:ty),+) => {
+            impl_layout!($([] $t => $t;)+);
+        };
+    }
+
+    impl_layout!(
+        u8,
+        u16,
+        u32,
+        u64,
+        u128,
+        usize,
+        i8,
+        i16,
+        i32,
+        i64,
+        i128,
+        isize,
+        f32,
+        f64,
+        bool,
+        char,
+        ()
+    );
+
+    // Pointers to sized types are thin
+    impl_layout! {
+        ['a, T] &'a T => &'static ();
+        ['a, T] &'a mut T => &'static ();
+        [T] *const T => *const ();
+        [T] *mut T => *const ();
+    }
+
+    impl<T: ?Sized> ConstLayout for PhantomData<T> {
+        type Size = Const<0>;
+        type Align = Const<1>;
+    }
+
+    impl<const N: i32> ConstLayout for Const<N> {
+        type Size = Const<0>;
+        type Align = Const<1>;
+    }
+
+    macro_rules! impl_const_size {
+        ($([$($g:tt)*] $t:ty => $size:expr;)+) => {
+            $(impl<$($g)*> ConstSize for $t {
+                type Size = Const<{ $size as i32 }>;
+                const SIZE: usize = $size;
+            })+
+        };
+        ($($t:ty),+) => {
+            impl_const_size!($([] $t => size_of::<$t>();)+);
+        };
+    }
+
+    // `usize` & `isize` aren't portable between the ends of a wire
+    impl_const_size!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, bool, char, ());
+
+    impl_const_size! {
+        [T: ?Sized] PhantomData<T> => 0;
+        [const N: i32] Const<N> => 0;
+    }
+}
+
+impl<T: ConstLayout> ConstLayout for [T; 0] {
+    type Size = Const<0>;
+    type Align = T::Align;
+}
+
+macro_rules! impl_array_layout {
+    ($($n:literal),+) => {
+        $(impl<T: ConstLayout> ConstLayout for [T; $n]
+        where
+            T::Size: Mul<Const<$n>>,
+        {
+            type Size = Prod<T::Size, Const<$n>>;
+            type Align = T::Align;
+        })+
+    };
+}
+
+with_table!(impl_array_layout);
+
+/// The size of `Self` on a wire as `Const`: the sum of the sizes of its fields without padding, see [`WireSize`].
+///
+/// Implemented for fixed-size primitives, arrays in the conversion table, `PhantomData` & `Const`.
+/// `#[derive(ConstSize)]` of the `macros` feature implements it for structs.
+#[diagnostic::on_unimplemented(
+    message = "the wire size of `{Self}` isn't known at the type level",
+    label = "doesn't implement `ConstSize`",
+    note = "derive `ConstSize` for structs, or declare the size of the field with `#[const_size(N)]`"
+)]
+pub trait ConstSize {
+    type Size;
+    /// The value of `Size`
+    const SIZE: usize;
+}
+
+/// The size of `T` on a wire as `Const`
+pub type WireSize<T> = <T as ConstSize>::Size;
+
+impl<T: ConstSize> ConstSize for [T; 0] {
+    type Size = Const<0>;
+    const SIZE: usize = 0;
+}
+
+macro_rules! impl_array_size {
+    ($($n:literal),+) => {
+        $(impl<T: ConstSize> ConstSize for [T; $n]
+        where
+            T::Size: Mul<Const<$n>>,
+        {
+            type Size = Prod<T::Size, Const<$n>>;
+            const SIZE: usize = T::SIZE * $n;
+        })+
+    };
+}
+
+with_table!(impl_array_size);
+
+/// The upper bound of the size of `Self` serialized by `postcard` as `Const`, see [`MaxSerialized`].
+///
+/// Integers are varints, so they take up to 1 byte more than their size, & enums are prefixed
+/// by the varint of the variant index. Implemented for primitives, `Option`, `Result`, arrays in
+/// the conversion table, `PhantomData` & `Const`. `#[derive(MaxSerializedSize)]` of the `macros`
+/// feature implements it for structs & enums.
+#[diagnostic::on_unimplemented(
+    message = "the maximum serialized size of `{Self}` isn't known at the type level",
+    label = "doesn't implement `MaxSerializedSize`",
+    note = "derive `MaxSerializedSize` for structs & enums, or declare the size of the field with `#[max_size(N)]`"
+)]
+pub trait MaxSerializedSize {
+    type Size;
+    /// The value of `Size`
+    const MAX_SIZE: usize;
+}
+
+/// The upper bound of the size of `T` serialized by `postcard` as `Const`
+pub type MaxSerialized<T> = <T as MaxSerializedSize>::Size;
+
+/// The maximum size of the varint of an unsigned integer of `bytes`: 7 bits per byte
+const fn varint(bytes: usize) -> usize {
+    (bytes * 8).div_ceil(7)
+}
+
+// Varints aren't larger than 19 bytes
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+mod serialized {
+    use super::{size_of, varint, Const, MaxSerializedSize, PhantomData};
+
+    macro_rules! impl_max_size {
+        ($([$($g:tt)*] $t:ty => $size:expr;)+) => {
+            $(impl<$($g)*> MaxSerializedSize for $t {
+                type Size = Const<{ $size as i32 }>;
+                const MAX_SIZE: usize = $size;
+            })+
+        };
+        (varint: $($t:ty),+) => {
+            impl_max_size!($([] $t => varint(size_of::<$t>());)+);
+        };
+    }
+
+    impl_max_size!(varint: u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+
+    // `char` is a string of up to 4 bytes prefixed by its length
+    impl_max_size! {
+        [] u8 => 1;
+        [] i8 => 1;
+        [] bool => 1;
+        [] f32 => 4;
+        [] f64 => 8;
+        [] char => 5;
+        [] () => 0;
+        [T: ?Sized] PhantomData<T> => 0;
+        [const N: i32] Const<N> => varint(size_of::<i32>());
+    }
+}
+
+impl<T: MaxSerializedSize> MaxSerializedSize for Option<T>
+where
+    T::Size: Add<Const<1>>,
+{
+    type Size = Sum<T::Size, Const<1>>;
+    const MAX_SIZE: usize = T::MAX_SIZE + 1;
+}
+
+impl<T: MaxSerializedSize, E: MaxSerializedSize> MaxSerializedSize for Result<T, E>
+where
+    T::Size: Max<E::Size>,
+    Maximum<T::Size, E::Size>: Add<Const<1>>,
+{
+    type Size = Sum<Maximum<T::Size, E::Size>, Const<1>>;
+    const MAX_SIZE: usize = 1 + if T::MAX_SIZE > E::MAX_SIZE { T::MAX_SIZE } else { E::MAX_SIZE };
+}
+
+impl<T: MaxSerializedSize> MaxSerializedSize for [T; 0] {
+    type Size = Const<0>;
+    const MAX_SIZE: usize = 0;
+}
+
+macro_rules! impl_array_max_size {
+    ($($n:literal),+) => {
+        $(impl<T: MaxSerializedSize> MaxSerializedSize for [T; $n]
+        where
+            T::Size: Mul<Const<$n>>,
+        {
+            type Size = Prod<T::Size, Const<$n>>;
+            const MAX_SIZE: usize = T::MAX_SIZE * $n;
+        })+
+    };
+}
+
+with_table!(impl_array_max_size);
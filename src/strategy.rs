@@ -0,0 +1,41 @@
+//! `proptest` strategies covering the conversion table
+
+use crate::{dispatch::DynConst, TABLE_MAX};
+use core::ops::RangeInclusive;
+use proptest::{
+    arbitrary::Arbitrary,
+    strategy::{Map, Strategy},
+};
+
+/// Every value of the conversion table, `-MAX..=MAX`
+#[must_use]
+pub fn table_values() -> RangeInclusive<i32> {
+    -TABLE_MAX..=TABLE_MAX
+}
+
+/// Every value of the conversion table, which is `-MAX..=MAX`, as `DynConst`.
+///
+/// ```rust
+/// use proptest::prelude::*;
+/// use typenum_alias::{dispatch::DynConst, strategy::dyn_consts};
+///
+/// proptest! {
+///     fn roundtrip(n in dyn_consts()) {
+///         prop_assert_eq!(DynConst::try_from(n.get()), Ok(n));
+///     }
+/// }
+/// roundtrip();
+/// ```
+#[allow(clippy::missing_panics_doc)] // The values are in the table
+pub fn dyn_consts() -> Map<RangeInclusive<i32>, fn(i32) -> DynConst> {
+    table_values().prop_map(|n| DynConst::new(n).expect("the value is in the table"))
+}
+
+impl Arbitrary for DynConst {
+    type Parameters = ();
+    type Strategy = Map<RangeInclusive<i32>, fn(i32) -> DynConst>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        dyn_consts()
+    }
+}
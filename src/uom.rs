@@ -0,0 +1,97 @@
+//! Dimension exponents of `uom` as `Const`: `Isq<1, 0, -2, 0, 0, 0, 0>` instead of
+//! `ISQ<P1, Z0, N2, Z0, Z0, Z0, Z0>`
+
+use crate::{assert::ConstEq, Const, Constify, Normalize, Typenum};
+use uom::{
+    si::{Dimension, Quantity, Units, ISQ, SI},
+    Conversion,
+};
+
+/// The dimension of the ISQ with the exponents of length, mass, time, electric current, thermodynamic temperature,
+/// amount of substance & luminous intensity as `Const`
+pub type Isq<
+    const L: i32,
+    const M: i32,
+    const T: i32,
+    const I: i32,
+    const TH: i32,
+    const N: i32,
+    const J: i32,
+> = ISQ<
+    Typenum<Const<L>>,
+    Typenum<Const<M>>,
+    Typenum<Const<T>>,
+    Typenum<Const<I>>,
+    Typenum<Const<TH>>,
+    Typenum<Const<N>>,
+    Typenum<Const<J>>,
+>;
+
+/// A quantity in SI base units with the dimension exponents as `Const`, see [`Isq`]
+pub type Si<
+    V,
+    const L: i32,
+    const M: i32,
+    const T: i32,
+    const I: i32,
+    const TH: i32,
+    const N: i32,
+    const J: i32,
+> = Quantity<Isq<L, M, T, I, TH, N, J>, SI<V>, V>;
+
+/// The exponents of a dimension as a tuple of `Const`, in the order of [`Isq`]
+pub type Exponents<D> = (
+    Constify<<D as Dimension>::L>,
+    Constify<<D as Dimension>::M>,
+    Constify<<D as Dimension>::T>,
+    Constify<<D as Dimension>::I>,
+    Constify<<D as Dimension>::Th>,
+    Constify<<D as Dimension>::N>,
+    Constify<<D as Dimension>::J>,
+);
+
+/// Implemented by quantities with the dimension exponents `E`, which is a tuple of `Const` in the order of [`Isq`].
+/// Mismatches fail with both exponents as `Const`, rather than as `typenum` integers.
+///
+/// ```rust
+/// use uom::si::{f64::{Length, Time}, length::meter, time::second};
+/// use typenum_alias::{uom::{DimensionIs, Si}, Const};
+///
+/// type C0 = Const<0>;
+/// type Velocity = Si<f64, 1, 0, -1, 0, 0, 0, 0>;
+///
+/// fn speed<Q: DimensionIs<(Const<1>, C0, Const<-1>, C0, C0, C0, C0)>>(_: Q) {}
+///
+/// let v: Velocity = Length::new::<meter>(3.0) / Time::new::<second>(1.0);
+/// speed(v);
+/// ```
+///
+/// ```rust,compile_fail
+/// use uom::si::{f64::{Length, Time}, length::meter, time::second};
+/// use typenum_alias::{uom::DimensionIs, Const};
+///
+/// type C0 = Const<0>;
+///
+/// fn speed<Q: DimensionIs<(Const<1>, C0, Const<-1>, C0, C0, C0, C0)>>(_: Q) {}
+///
+/// // error: `(Const<1>, Const<0>, Const<-2>, ...)` isn't equal to `(Const<1>, Const<0>, Const<-1>, ...)`
+/// let t = Time::new::<second>(1.0);
+/// speed(Length::new::<meter>(3.0) / t / t);
+/// ```
+pub trait DimensionIs<E> {}
+
+impl<D, U, V, E> DimensionIs<E> for Quantity<D, U, V>
+where
+    D: Dimension + ?Sized,
+    U: Units<V> + ?Sized,
+    V: uom::num::Num + Conversion<V>,
+    D::L: Normalize,
+    D::M: Normalize,
+    D::T: Normalize,
+    D::I: Normalize,
+    D::Th: Normalize,
+    D::N: Normalize,
+    D::J: Normalize,
+    Exponents<D>: ConstEq<E>,
+{
+}
@@ -0,0 +1,47 @@
+use typenum::{Bit, Integer, NInt, NonZero, PInt, UInt, UTerm, Unsigned, Z0};
+
+/// The value of a `typenum` integer or unsigned as associated consts, the same for both kinds.
+/// The names differ from `Integer::I64` & `Unsigned::USIZE`, so they aren't ambiguous with those in scope.
+///
+/// Array lengths can't depend on generic parameters on stable Rust, so `[0u8; T::LEN]` needs a concrete `T`.
+///
+/// ```rust
+/// use typenum_alias::TypenumValue;
+/// use typenum::{Integer, Unsigned, N3, P8, U32};
+///
+/// let buf = [0u8; U32::LEN];
+/// assert_eq!(buf.len(), 32);
+/// assert_eq!((P8::LEN, P8::I32), (8, 8));
+/// assert_eq!(N3::VALUE, -3);
+/// assert_eq!(U32::USIZE, U32::LEN);
+/// ```
+pub trait TypenumValue {
+    const VALUE: i64;
+    /// The value as `usize`, fails to compile for negative values
+    const LEN: usize;
+}
+
+impl TypenumValue for Z0 {
+    const VALUE: i64 = 0;
+    const LEN: usize = 0;
+}
+
+impl<U: Unsigned + NonZero> TypenumValue for PInt<U> {
+    const VALUE: i64 = <Self as Integer>::I64;
+    const LEN: usize = U::USIZE;
+}
+
+impl<U: Unsigned + NonZero> TypenumValue for NInt<U> {
+    const VALUE: i64 = <Self as Integer>::I64;
+    const LEN: usize = panic!("negative values don't fit into `usize`");
+}
+
+impl TypenumValue for UTerm {
+    const VALUE: i64 = 0;
+    const LEN: usize = 0;
+}
+
+impl<U: Unsigned, B: Bit> TypenumValue for UInt<U, B> {
+    const VALUE: i64 = <Self as Unsigned>::I64;
+    const LEN: usize = <Self as Unsigned>::USIZE;
+}
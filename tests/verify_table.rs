@@ -0,0 +1,23 @@
+//! Checks the operations of `Const` against `i32` arithmetic, generated by `build.rs`.
+//! Run with `cargo test --features verify-table`, `TYPENUM_ALIAS_VERIFY_RANGE` sets the range of the operands.
+
+#![cfg(feature = "verify-table")]
+// The generated checks compute with literals
+#![allow(clippy::identity_op, clippy::erasing_op, clippy::eq_op, clippy::neg_multiply)]
+
+use typenum::{Compare, Diff, Gcf, Integer, Maximum, Minimum, Ord, Prod, Quot, Sum};
+use typenum_alias::{Const, ToTypenum, Typenum};
+
+fn value<N: ToTypenum>() -> i32 {
+    Typenum::<N>::I32
+}
+
+fn gcd_i32(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd_i32(b, a % b)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/verify.rs"));